@@ -1,10 +1,10 @@
 use proc_macro2::Span;
 use syn::{
-    Attribute, Error, Expr, ExprLit, Generics, Ident, Lit, Meta, Token, Visibility, parenthesized,
-    punctuated::Punctuated,
+    Attribute, Error, Expr, ExprLit, Generics, Ident, ItemStruct, Lit, LitStr, Meta, Token, Type,
+    Visibility, parenthesized, punctuated::Punctuated,
 };
 
-use crate::{Ast, Fields};
+use crate::{Ast, Endian, FieldConfig, Fields, backing_width};
 
 /// Analyse the struct, and produce a model for future usage.
 pub fn analyse(ast: Ast) -> Result<DeriveModel, Error> {
@@ -12,66 +12,667 @@ pub fn analyse(ast: Ast) -> Result<DeriveModel, Error> {
     let config = DeriveConfig::try_from(ast.attrs())?;
 
     Ok(match ast {
-        Ast::Struct(item_struct) => DeriveModel {
-            name: item_struct.ident.clone(),
-            visibility: item_struct.vis,
-            item: DeriveModelItem::Struct {
-                fields: Fields::try_from(&item_struct.fields)?,
-                generics: item_struct.generics,
-                container_assert_layout: config.container_assert_layout,
-            },
-        },
-        Ast::Enum(item_enum) => DeriveModel {
-            name: item_enum.ident.clone(),
-            visibility: item_enum.vis,
-            item: DeriveModelItem::Enum {
-                repr: config.repr.ok_or(Error::new(
+        _ if config.try_from.is_some() || config.into.is_some() => analyse_conversion(ast, config)?,
+        Ast::Struct(_) if config.union => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`union` is only supported on enums",
+            ));
+        }
+        Ast::Struct(item_struct) if config.flags => analyse_flags(item_struct, config)?,
+        Ast::Struct(item_struct) => {
+            let mut fields = Fields::try_from(&item_struct.fields)?;
+            if let Some(attribute) = fields.configs().into_iter().find_map(flags_only_attribute) {
+                return Err(Error::new(
                     Span::call_site(),
-                    "'repr = ...' attribute is missing",
-                ))?,
-                variants: item_enum
-                    .variants
-                    .into_iter()
-                    .map(|variant| {
-                        if !matches!(variant.fields, syn::Fields::Unit) {
-                            return Err(Error::new_spanned(
-                                variant.fields,
-                                "enum variants must be unit",
-                            ));
-                        }
+                    format!("`{attribute}` is only supported within `flags` structs"),
+                ));
+            }
+            check_skipped_fields(&fields)?;
+            check_union_tags(&fields, config.view || config.view_mut)?;
+            check_bit_fields(&fields, config.view || config.view_mut)?;
+            check_padding(&fields, &item_struct.generics, config.c_alignment)?;
+            if config.transparent {
+                check_transparent(&fields, &config)?;
+            }
+            if config.c_alignment {
+                fields.natural_alignment();
+            }
+            if config.size.is_some() && !item_struct.generics.params.is_empty() {
+                return Err(Error::new_spanned(
+                    &item_struct.generics,
+                    "`size` is not supported on generic structs",
+                ));
+            }
+            fields.default_endian(config.endian);
+
+            DeriveModel {
+                name: item_struct.ident.clone(),
+                visibility: item_struct.vis,
+                item: DeriveModelItem::Struct {
+                    fields,
+                    generics: item_struct.generics,
+                    container_assert_layout: config.container_assert_layout,
+                    view: config.view,
+                    view_mut: config.view_mut,
+                    endian: config.endian,
+                    size: config.size.map(Box::new),
+                    c_alignment: config.c_alignment,
+                    transparent: config.transparent,
+                },
+            }
+        }
+        Ast::Enum(_) if config.view || config.view_mut => {
+            return Err(Error::new(
+                Span::call_site(),
+                "views are only supported on structs",
+            ));
+        }
+        Ast::Enum(_) if config.size.is_some() || config.c_alignment || config.transparent => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`size`, `c_alignment`, and `transparent` are only supported on structs",
+            ));
+        }
+        Ast::Enum(item_enum) => {
+            let mut variants = Vec::with_capacity(item_enum.variants.len());
+            let mut other = None;
+
+            for variant in item_enum.variants {
+                if is_other_variant(&variant.attrs)? {
+                    if other.is_some() {
+                        return Err(Error::new_spanned(
+                            &variant,
+                            "only one variant can be marked with `other`",
+                        ));
+                    }
+
+                    if let Some((_, discriminant)) = &variant.discriminant {
+                        return Err(Error::new_spanned(
+                            discriminant,
+                            "`other` variant cannot have a discriminant",
+                        ));
+                    }
+
+                    if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+                    {
+                        return Err(Error::new_spanned(
+                            variant.fields,
+                            "`other` variant must have a single unnamed field holding the raw value",
+                        ));
+                    }
+
+                    other = Some(variant.ident);
+                    continue;
+                }
 
-                        let value = variant
-                            .discriminant
-                            .as_ref()
-                            // Extract the literal
-                            .and_then(|(_, discriminant)| {
-                                if let Expr::Lit(ExprLit { lit, .. }) = discriminant {
-                                    Some(lit)
-                                } else {
-                                    None
-                                }
-                            })
-                            .ok_or_else(|| Error::new_spanned(&variant, "discriminant required"))
-                            // Parse the literal
-                            .and_then(|lit| match lit {
-                                Lit::Int(value) => value.base10_parse().map_err(|_| {
-                                    Error::new_spanned(value, "cannot parse discriminant")
-                                }),
-                                Lit::Byte(value) => Ok(value.value() as usize),
-                                _ => Err(Error::new_spanned(
-                                    lit,
-                                    "only int or byte literal discriminants are supported",
-                                )),
-                            })?;
-
-                        Ok((variant.ident, value))
+                let value = variant
+                    .discriminant
+                    .as_ref()
+                    // Extract the literal
+                    .and_then(|(_, discriminant)| {
+                        if let Expr::Lit(ExprLit { lit, .. }) = discriminant {
+                            Some(lit)
+                        } else {
+                            None
+                        }
                     })
-                    .collect::<Result<_, _>>()?,
-            },
+                    .ok_or_else(|| Error::new_spanned(&variant, "discriminant required"))
+                    // Parse the literal
+                    .and_then(|lit| match lit {
+                        Lit::Int(value) => value
+                            .base10_parse()
+                            .map_err(|_| Error::new_spanned(value, "cannot parse discriminant")),
+                        Lit::Byte(value) => Ok(value.value() as usize),
+                        _ => Err(Error::new_spanned(
+                            lit,
+                            "only int or byte literal discriminants are supported",
+                        )),
+                    })?;
+
+                let mut fields = Fields::try_from(&variant.fields)?;
+                fields.default_endian(config.endian);
+                if fields.has_assertions() {
+                    return Err(Error::new_spanned(
+                        variant.fields,
+                        "layout assertions are not supported on variant fields",
+                    ));
+                }
+                if let Some(attribute) =
+                    fields.configs().into_iter().find_map(struct_only_attribute)
+                {
+                    return Err(Error::new_spanned(
+                        variant.fields,
+                        format!("`{attribute}` is not supported on variant fields"),
+                    ));
+                }
+
+                variants.push(Variant {
+                    ident: variant.ident,
+                    discriminant: value,
+                    fields,
+                });
+            }
+
+            if other.is_some() && config.union {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`other` is not supported on unions",
+                ));
+            }
+
+            if other.is_some() && variants.iter().any(Variant::has_fields) {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`other` is not supported on enums with data-carrying variants",
+                ));
+            }
+
+            DeriveModel {
+                name: item_enum.ident.clone(),
+                visibility: item_enum.vis,
+                item: DeriveModelItem::Enum {
+                    repr: config.repr.ok_or(Error::new(
+                        Span::call_site(),
+                        "'repr = ...' attribute is missing",
+                    ))?,
+                    variants,
+                    other,
+                    union: config.union,
+                    endian: config.endian,
+                },
+            }
+        }
+    })
+}
+
+/// Ensure that every `union_tag` refers to a named field declared before the union field, so that
+/// the tag is available when converting the union.
+fn check_union_tags(fields: &Fields, view: bool) -> Result<(), Error> {
+    match fields {
+        Fields::Named(fields) => {
+            for (i, (ident, _, config)) in fields.iter().enumerate() {
+                let Some(tag) = &config.union_tag else {
+                    continue;
+                };
+
+                if view {
+                    return Err(Error::new_spanned(
+                        ident,
+                        "`union_tag` is not supported alongside views",
+                    ));
+                }
+
+                let Some((_, _, tag_config)) = fields[..i]
+                    .iter()
+                    .find(|(field, _, config)| field == tag && !config.skip)
+                else {
+                    return Err(Error::new_spanned(
+                        tag,
+                        "`union_tag` must name a field declared before the union, which isn't skipped",
+                    ));
+                };
+
+                // The tag is read back out of its raw value, which bit fields don't have.
+                if tag_config.bits.is_some() {
+                    return Err(Error::new_spanned(
+                        tag,
+                        "`union_tag` can't name a bit field",
+                    ));
+                }
+
+                // The tag is converted as its own type, which `with` replaces with another.
+                if tag_config.with.is_some() {
+                    return Err(Error::new_spanned(
+                        tag,
+                        "`union_tag` can't name a field converted `with` a module",
+                    ));
+                }
+            }
+        }
+        Fields::Unnamed(fields) => {
+            if fields.iter().any(|(_, config)| config.union_tag.is_some()) {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`union_tag` requires named fields",
+                ));
+            }
+        }
+        Fields::Unit => {}
+    }
+
+    Ok(())
+}
+
+/// Ensure that skipped fields don't have any attributes affecting the raw struct, that only
+/// skipped fields provide a `default`, and that `with` is only used on plain fields.
+fn check_skipped_fields(fields: &Fields) -> Result<(), Error> {
+    for config in fields.configs() {
+        if config.default.is_some() && !config.skip {
+            return Err(Error::new(Span::call_site(), "`default` requires `skip`"));
+        }
+
+        if config.skip
+            && (config.assert_layout.is_some()
+                || config.union_tag.is_some()
+                || config.bits.is_some()
+                || config.backing.is_some()
+                || config.endian.is_some()
+                || config.offset.is_some()
+                || config.align.is_some()
+                || config.with.is_some())
+        {
+            return Err(Error::new(
+                Span::call_site(),
+                "skipped fields don't appear in the raw struct, so can't have layout attributes",
+            ));
+        }
+
+        if config.with.is_some() && (config.union_tag.is_some() || config.bits.is_some()) {
+            return Err(Error::new(
+                Span::call_site(),
+                "`with` can't be used on union or bit fields",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure that bit fields form valid groups. A group starts at a field declaring its `backing`
+/// integer, and continues through the following `bits` fields without their own `backing`.
+fn check_bit_fields(fields: &Fields, view: bool) -> Result<(), Error> {
+    let fields = match fields {
+        Fields::Named(fields) => fields,
+        Fields::Unnamed(fields) => {
+            if fields
+                .iter()
+                .any(|(_, config)| config.bits.is_some() || config.backing.is_some())
+            {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "bit fields require named fields",
+                ));
+            }
+
+            return Ok(());
+        }
+        Fields::Unit => return Ok(()),
+    };
+
+    // Bits remaining in the current group.
+    let mut remaining = None;
+
+    for (ident, _, config) in fields {
+        let Some(bits) = config.bits else {
+            if config.backing.is_some() {
+                return Err(Error::new_spanned(ident, "`backing` requires `bits`"));
+            }
+
+            remaining = None;
+            continue;
+        };
+
+        if view {
+            return Err(Error::new_spanned(
+                ident,
+                "bit fields are not supported alongside views",
+            ));
+        }
+
+        if bits == 0 {
+            return Err(Error::new_spanned(
+                ident,
+                "bit fields must be at least 1 bit",
+            ));
+        }
+
+        let available = match &config.backing {
+            Some(backing) => backing_width(backing).ok_or_else(|| {
+                Error::new_spanned(backing, "`backing` must be an unsigned integer type")
+            })?,
+            None => {
+                if config.assert_layout.is_some()
+                    || config.endian.is_some()
+                    || config.offset.is_some()
+                    || config.align.is_some()
+                {
+                    return Err(Error::new_spanned(
+                        ident,
+                        "layout assertions, offsets, alignment, and byte order for a group of bit fields must be on its first field",
+                    ));
+                }
+
+                remaining.ok_or_else(|| {
+                    Error::new_spanned(
+                        ident,
+                        "the first field of a group of bit fields must declare its `backing` integer",
+                    )
+                })?
+            }
+        };
+
+        remaining = Some(available.checked_sub(bits).ok_or_else(|| {
+            Error::new_spanned(
+                ident,
+                "bit field exceeds the width of its `backing` integer",
+            )
+        })?);
+    }
+
+    Ok(())
+}
+
+/// Ensure that padding for field offsets and alignment can be computed. Padding is inserted as
+/// named fields, and the size of each field can't depend on generics.
+fn check_padding(fields: &Fields, generics: &Generics, c_alignment: bool) -> Result<(), Error> {
+    let configs = fields.configs();
+    if configs
+        .iter()
+        .any(|config| config.offset.is_some() && config.align.is_some())
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            "`offset` and `align` cannot be used on the same field",
+        ));
+    }
+
+    if !c_alignment
+        && !configs
+            .iter()
+            .any(|config| config.offset.is_some() || config.align.is_some())
+    {
+        return Ok(());
+    }
+
+    if !matches!(fields, Fields::Named(_)) {
+        return Err(Error::new(
+            Span::call_site(),
+            "`offset`, `align`, and `c_alignment` require named fields",
+        ));
+    }
+
+    if !generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            generics,
+            "`offset`, `align`, and `c_alignment` are not supported on generic structs",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Name of the first attribute on a field which is only supported within structs.
+fn struct_only_attribute(config: &FieldConfig) -> Option<&'static str> {
+    if config.union_tag.is_some() {
+        return Some("union_tag");
+    }
+
+    if config.bits.is_some() || config.backing.is_some() {
+        return Some("bits");
+    }
+
+    if config.offset.is_some() {
+        return Some("offset");
+    }
+
+    if config.align.is_some() {
+        return Some("align");
+    }
+
+    if config.skip || config.default.is_some() {
+        return Some("skip");
+    }
+
+    if config.with.is_some() {
+        return Some("with");
+    }
+
+    None
+}
+
+/// Name of the first attribute on a field which is only supported within `flags` structs.
+fn flags_only_attribute(config: &FieldConfig) -> Option<&'static str> {
+    if config.bit.is_some() {
+        return Some("bit");
+    }
+
+    if config.unknown_bits {
+        return Some("unknown_bits");
+    }
+
+    None
+}
+
+/// Ensure that a `transparent` struct has a single field which isn't skipped, and that nothing
+/// would change the layout of the raw value of that field.
+fn check_transparent(fields: &Fields, config: &DeriveConfig) -> Result<(), Error> {
+    if config.view
+        || config.view_mut
+        || config.container_assert_layout.is_some()
+        || config.size.is_some()
+        || config.c_alignment
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            "`transparent` can't be combined with views, layout assertions, `size`, or `c_alignment`",
+        ));
+    }
+
+    let configs = fields
+        .configs()
+        .into_iter()
+        .filter(|config| !config.skip)
+        .collect::<Vec<_>>();
+    let [config] = configs.as_slice() else {
+        return Err(Error::new(
+            Span::call_site(),
+            "`transparent` requires exactly one field which isn't skipped",
+        ));
+    };
+
+    if config.assert_layout.is_some()
+        || config.union_tag.is_some()
+        || config.bits.is_some()
+        || config.offset.is_some()
+        || config.align.is_some()
+        || config.with.is_some()
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            "the field of a `transparent` struct only supports `endian`",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Analyse a container converted through another type with `try_from` and `into`, where the fields
+/// or variants are left to the conversions.
+fn analyse_conversion(ast: Ast, config: DeriveConfig) -> Result<DeriveModel, Error> {
+    let (Some(try_from), Some(into)) = (config.try_from, config.into) else {
+        return Err(Error::new(
+            Span::call_site(),
+            "`try_from` and `into` must be used together",
+        ));
+    };
+
+    if config.repr.is_some()
+        || config.container_assert_layout.is_some()
+        || config.view
+        || config.view_mut
+        || config.union
+        || config.flags
+        || config.unknown_bits.is_some()
+        || config.size.is_some()
+        || config.c_alignment
+        || config.transparent
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            "`try_from` and `into` can only be combined with `endian`",
+        ));
+    }
+
+    let (name, visibility, generics) = match ast {
+        Ast::Struct(item_struct) => (item_struct.ident, item_struct.vis, item_struct.generics),
+        Ast::Enum(item_enum) => (item_enum.ident, item_enum.vis, item_enum.generics),
+    };
+    if !generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &generics,
+            "`try_from` and `into` are not supported on generic containers",
+        ));
+    }
+
+    Ok(DeriveModel {
+        name,
+        visibility,
+        item: DeriveModelItem::Conversion {
+            try_from: Box::new(try_from),
+            into: Box::new(into),
+            endian: config.endian,
+        },
+    })
+}
+
+/// Analyse a `flags` struct, where each field represents a single bit of an integer.
+fn analyse_flags(item_struct: ItemStruct, config: DeriveConfig) -> Result<DeriveModel, Error> {
+    if config.view
+        || config.view_mut
+        || config.container_assert_layout.is_some()
+        || config.size.is_some()
+        || config.c_alignment
+        || config.transparent
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            "views, layout assertions, `size`, `c_alignment`, and `transparent` are not supported on `flags` structs",
+        ));
+    }
+
+    if !item_struct.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item_struct.generics,
+            "`flags` structs cannot be generic",
+        ));
+    }
+
+    let repr = config.repr.ok_or(Error::new(
+        Span::call_site(),
+        "'repr = ...' attribute is missing",
+    ))?;
+    let width = repr.unsigned_width().ok_or(Error::new(
+        Span::call_site(),
+        "`flags` repr must be a fixed width unsigned integer",
+    ))?;
+    let unknown_bits = config.unknown_bits.unwrap_or(UnknownBits::Reject);
+
+    let Fields::Named(fields) = Fields::try_from(&item_struct.fields)? else {
+        return Err(Error::new_spanned(
+            &item_struct.fields,
+            "`flags` structs require named fields",
+        ));
+    };
+
+    let mut flags = Vec::with_capacity(fields.len());
+    let mut unknown_field = None;
+    for (ident, _, field_config) in fields {
+        if field_config.assert_layout.is_some()
+            || field_config.endian.is_some()
+            || struct_only_attribute(&field_config).is_some()
+        {
+            return Err(Error::new_spanned(
+                &ident,
+                "only `bit` and `unknown_bits` are supported within `flags` structs",
+            ));
+        }
+
+        match (field_config.bit, field_config.unknown_bits) {
+            (Some(bit), false) => {
+                if bit >= width {
+                    return Err(Error::new_spanned(
+                        &ident,
+                        format!("bit {bit} is outside of the {width} bit repr"),
+                    ));
+                }
+
+                if flags.iter().any(|(_, other)| *other == bit) {
+                    return Err(Error::new_spanned(
+                        &ident,
+                        format!("bit {bit} is used by multiple fields"),
+                    ));
+                }
+
+                flags.push((ident, bit));
+            }
+            (None, true) => {
+                if unknown_bits != UnknownBits::Preserve {
+                    return Err(Error::new_spanned(
+                        &ident,
+                        "`unknown_bits` field requires `unknown_bits = preserve`",
+                    ));
+                }
+
+                if unknown_field.replace(ident.clone()).is_some() {
+                    return Err(Error::new_spanned(
+                        &ident,
+                        "only one field can be marked with `unknown_bits`",
+                    ));
+                }
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    &ident,
+                    "fields of `flags` structs require exactly one of `bit = ...` or `unknown_bits`",
+                ));
+            }
+        }
+    }
+
+    if unknown_bits == UnknownBits::Preserve && unknown_field.is_none() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`unknown_bits = preserve` requires a field marked with `unknown_bits`",
+        ));
+    }
+
+    Ok(DeriveModel {
+        name: item_struct.ident,
+        visibility: item_struct.vis,
+        item: DeriveModelItem::Flags {
+            flags,
+            repr,
+            unknown_bits,
+            unknown_field,
+            endian: config.endian,
         },
     })
 }
 
+/// Determine whether a variant is marked as the catch-all for unknown discriminants
+/// (`#[cuisiner(other)]`).
+fn is_other_variant(attrs: &[Attribute]) -> Result<bool, Error> {
+    let mut other = false;
+
+    for attr in attrs {
+        if !attr.path().is_ident("cuisiner") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("other") {
+                other = true;
+                return Ok(());
+            }
+
+            Err(Error::new_spanned(&meta.path, "unknown attribute"))
+        })?;
+    }
+
+    Ok(other)
+}
+
 /// All information required to be pulled from the AST to implement the derive macro.
 #[derive(Clone)]
 pub struct DeriveModel {
@@ -91,13 +692,71 @@ pub enum DeriveModelItem {
         /// Generics present on the original struct.
         generics: Generics,
         container_assert_layout: Option<Vec<Meta>>,
+        /// Whether a zero-copy view type should be generated.
+        view: bool,
+        /// Whether a mutable zero-copy view type should be generated.
+        view_mut: bool,
+        /// Byte order of the whole struct, regardless of the caller's byte order.
+        endian: Option<Endian>,
+        /// Total size of the raw struct, with padding inserted after the last field to reach it.
+        size: Option<Box<Expr>>,
+        /// Whether fields are laid out with the alignment a C compiler would give them.
+        c_alignment: bool,
+        /// Whether the raw representation of the only field is used directly.
+        transparent: bool,
     },
     Enum {
-        /// All variants and their discriminant values.
-        variants: Vec<(Ident, usize)>,
+        /// All variants, with their discriminant values and fields.
+        variants: Vec<Variant>,
+        /// Variant which holds any unknown discriminant values.
+        other: Option<Ident>,
         /// Internal enum representation.
         repr: Repr,
+        /// Whether the enum is a union, with the tag stored outside of the raw representation.
+        union: bool,
+        /// Byte order of the tag, regardless of the caller's byte order.
+        endian: Option<Endian>,
+    },
+    Flags {
+        /// Field and bit for each flag.
+        flags: Vec<(Ident, u32)>,
+        /// Integer holding the flags.
+        repr: Repr,
+        /// Handling of bits which don't correspond to any flag.
+        unknown_bits: UnknownBits,
+        /// Field holding undefined bits, when they are preserved.
+        unknown_field: Option<Ident>,
+        /// Byte order of the flags word, regardless of the caller's byte order.
+        endian: Option<Endian>,
     },
+    Conversion {
+        /// Type which the raw value is converted into, before converting into the container.
+        try_from: Box<Type>,
+        /// Type which the container is converted into, before converting into the raw value.
+        into: Box<Type>,
+        /// Byte order of the whole container, regardless of the caller's byte order.
+        endian: Option<Endian>,
+    },
+}
+
+/// Enum variant, which may carry data.
+#[derive(Clone)]
+pub struct Variant {
+    pub ident: Ident,
+    pub discriminant: usize,
+    /// Fields carried by the variant, which will be laid out after the tag.
+    pub fields: Fields,
+}
+
+impl Variant {
+    /// Whether the variant carries any data.
+    pub fn has_fields(&self) -> bool {
+        match &self.fields {
+            Fields::Named(fields) => !fields.is_empty(),
+            Fields::Unnamed(fields) => !fields.is_empty(),
+            Fields::Unit => false,
+        }
+    }
 }
 
 /// Configuration provided via attributes.
@@ -106,6 +765,17 @@ pub enum DeriveModelItem {
 struct DeriveConfig {
     repr: Option<Repr>,
     container_assert_layout: Option<Vec<Meta>>,
+    view: bool,
+    view_mut: bool,
+    union: bool,
+    flags: bool,
+    unknown_bits: Option<UnknownBits>,
+    endian: Option<Endian>,
+    size: Option<Expr>,
+    c_alignment: bool,
+    try_from: Option<Type>,
+    into: Option<Type>,
+    transparent: bool,
 }
 
 #[cfg(test)]
@@ -145,6 +815,74 @@ impl TryFrom<&[Attribute]> for DeriveConfig {
                     return Ok(());
                 }
 
+                if meta.path.is_ident("view") {
+                    config.view = true;
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("endian") {
+                    config.endian = Some(Endian::parse(&meta)?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("transparent") {
+                    config.transparent = true;
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("try_from") {
+                    config.try_from = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("into") {
+                    config.into = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("c_alignment") {
+                    config.c_alignment = true;
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("size") {
+                    config.size = Some(meta.value()?.parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("flags") {
+                    config.flags = true;
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("unknown_bits") {
+                    config.unknown_bits = Some(UnknownBits::try_from(
+                        meta.value()?.parse::<Ident>()?.to_string().as_str(),
+                    )?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("union") {
+                    config.union = true;
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("view_mut") {
+                    config.view_mut = true;
+
+                    return Ok(());
+                }
+
                 if meta.path.is_ident("assert") {
                     let attrs;
                     parenthesized!(attrs in meta.input);
@@ -165,6 +903,36 @@ impl TryFrom<&[Attribute]> for DeriveConfig {
     }
 }
 
+/// Handling of bits in a flags word which don't correspond to any flag.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum UnknownBits {
+    /// Fail to parse values with undefined bits set.
+    Reject,
+    /// Discard undefined bits.
+    Drop,
+    /// Store undefined bits in a dedicated field, and write them back out.
+    Preserve,
+}
+
+impl TryFrom<&str> for UnknownBits {
+    type Error = Error;
+
+    fn try_from(unknown_bits: &str) -> Result<Self, Self::Error> {
+        match unknown_bits {
+            "reject" => Ok(Self::Reject),
+            "drop" => Ok(Self::Drop),
+            "preserve" => Ok(Self::Preserve),
+            unknown_bits => Err(Error::new(
+                Span::call_site(),
+                format!(
+                    "unknown `unknown_bits` policy: {unknown_bits} (expected reject, drop, or preserve)"
+                ),
+            )),
+        }
+    }
+}
+
 #[derive(Clone)]
 #[cfg_attr(test, derive(Debug, PartialEq, Eq))]
 pub enum Repr {
@@ -182,6 +950,20 @@ pub enum Repr {
     Isize,
 }
 
+impl Repr {
+    /// Width in bits, if the representation is a fixed width unsigned integer.
+    pub fn unsigned_width(&self) -> Option<u32> {
+        match self {
+            Repr::U8 => Some(8),
+            Repr::U16 => Some(16),
+            Repr::U32 => Some(32),
+            Repr::U64 => Some(64),
+            Repr::U128 => Some(128),
+            _ => None,
+        }
+    }
+}
+
 impl TryFrom<&str> for Repr {
     type Error = Error;
 
@@ -224,6 +1006,12 @@ mod test {
             fields,
             generics: _,
             container_assert_layout: _,
+            view: _,
+            view_mut: _,
+            endian: _,
+            size: _,
+            c_alignment: _,
+            transparent: _,
         } = &model.item
         else {
             panic!("expected struct derive model item");
@@ -242,12 +1030,18 @@ mod test {
 
     fn test_analyse_enum(ast: Ast, expected_repr: Repr, expected_variants: &[(Ident, usize)]) {
         let model = analyse(ast).unwrap();
-        let DeriveModelItem::Enum { variants, repr } = model.item else {
+        let DeriveModelItem::Enum { variants, repr, .. } = model.item else {
             panic!("expected enum derive model item");
         };
 
         assert_eq!(repr, expected_repr);
-        assert_eq!(variants, expected_variants);
+        assert_eq!(
+            variants
+                .into_iter()
+                .map(|variant| (variant.ident, variant.discriminant))
+                .collect::<Vec<_>>(),
+            expected_variants
+        );
     }
 
     #[test]
@@ -300,11 +1094,64 @@ mod test {
     }
 
     #[test]
-    fn analyse_valid_enum() {
-        test_analyse_enum(
-            Ast::Enum(parse_quote! {
-                #[cuisiner(repr = u32)]
-                enum MyEnum {
+    fn analyse_view_struct() {
+        let model = analyse(Ast::Struct(parse_quote! {
+            #[cuisiner(view)]
+            struct MyStruct {
+                a: u32,
+            }
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            model.item,
+            DeriveModelItem::Struct {
+                view: true,
+                view_mut: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn analyse_view_mut_struct() {
+        let model = analyse(Ast::Struct(parse_quote! {
+            #[cuisiner(view_mut)]
+            struct MyStruct {
+                a: u32,
+            }
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            model.item,
+            DeriveModelItem::Struct {
+                view: false,
+                view_mut: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn enum_view() {
+        assert!(
+            analyse(Ast::Enum(parse_quote! {
+                #[cuisiner(repr = u32, view)]
+                enum MyEnum {
+                    First = 1,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn analyse_valid_enum() {
+        test_analyse_enum(
+            Ast::Enum(parse_quote! {
+                #[cuisiner(repr = u32)]
+                enum MyEnum {
                     First = 1,
                     Second = 2,
                     Third = 3,
@@ -319,6 +1166,670 @@ mod test {
         );
     }
 
+    #[test]
+    fn analyse_enum_with_other() {
+        let model = analyse(Ast::Enum(parse_quote! {
+            #[cuisiner(repr = u32)]
+            enum MyEnum {
+                First = 1,
+                #[cuisiner(other)]
+                Unknown(u32),
+            }
+        }))
+        .unwrap();
+
+        let DeriveModelItem::Enum {
+            variants, other, ..
+        } = model.item
+        else {
+            panic!("expected enum derive model item");
+        };
+
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].ident, "First");
+        assert_eq!(other, Some(parse_quote!(Unknown)));
+    }
+
+    #[test]
+    fn enum_multiple_other() {
+        assert!(
+            analyse(Ast::Enum(parse_quote! {
+                #[cuisiner(repr = u32)]
+                enum MyEnum {
+                    #[cuisiner(other)]
+                    Unknown(u32),
+                    #[cuisiner(other)]
+                    AlsoUnknown(u32),
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn enum_other_without_field() {
+        assert!(
+            analyse(Ast::Enum(parse_quote! {
+                #[cuisiner(repr = u32)]
+                enum MyEnum {
+                    First = 1,
+                    #[cuisiner(other)]
+                    Unknown,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn analyse_data_enum() {
+        let model = analyse(Ast::Enum(parse_quote! {
+            #[cuisiner(repr = u8)]
+            enum MyEnum {
+                First(u32) = 1,
+                Second { a: u8, b: u16 } = 2,
+                Third = 3,
+            }
+        }))
+        .unwrap();
+
+        let DeriveModelItem::Enum { variants, .. } = model.item else {
+            panic!("expected enum derive model item");
+        };
+
+        assert_eq!(
+            variants.iter().map(Variant::has_fields).collect::<Vec<_>>(),
+            [true, true, false]
+        );
+    }
+
+    #[test]
+    fn data_enum_with_other() {
+        assert!(
+            analyse(Ast::Enum(parse_quote! {
+                #[cuisiner(repr = u8)]
+                enum MyEnum {
+                    First(u32) = 1,
+                    #[cuisiner(other)]
+                    Unknown(u8),
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn analyse_union() {
+        let model = analyse(Ast::Enum(parse_quote! {
+            #[cuisiner(union, repr = u8)]
+            enum MyUnion {
+                First(u32) = 1,
+                Second(u16) = 2,
+            }
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            model.item,
+            DeriveModelItem::Enum { union: true, .. }
+        ));
+    }
+
+    #[test]
+    fn union_struct() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(union)]
+                struct MyStruct {
+                    a: u32,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn union_tag() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    kind: u8,
+                    #[cuisiner(union_tag = kind)]
+                    body: MyUnion,
+                }
+            }))
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn union_tag_after_union() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(union_tag = kind)]
+                    body: MyUnion,
+                    kind: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn union_tag_bit_field() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bits = 4, backing = u8)]
+                    kind: u8,
+                    #[cuisiner(bits = 4)]
+                    version: u8,
+                    #[cuisiner(union_tag = kind)]
+                    body: MyUnion,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn union_tag_with_module() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(with = kind_module)]
+                    kind: Kind,
+                    #[cuisiner(union_tag = kind)]
+                    body: MyUnion,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn union_tag_with_view() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(view)]
+                struct MyStruct {
+                    kind: u8,
+                    #[cuisiner(union_tag = kind)]
+                    body: MyUnion,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn bit_fields() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bits = 3, backing = u8, assert(offset = 0))]
+                    version: u8,
+                    #[cuisiner(bits = 5)]
+                    flags: u8,
+                    #[cuisiner(bits = 16, backing = u16)]
+                    length: u16,
+                }
+            }))
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn bit_fields_without_backing() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    a: u8,
+                    #[cuisiner(bits = 3)]
+                    version: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn bit_fields_exceed_backing() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bits = 3, backing = u8)]
+                    version: u8,
+                    #[cuisiner(bits = 6)]
+                    flags: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn bit_fields_signed_backing() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bits = 3, backing = i8)]
+                    version: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn bit_fields_assert_within_group() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bits = 3, backing = u8)]
+                    version: u8,
+                    #[cuisiner(bits = 5, assert(offset = 0))]
+                    flags: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn analyse_flags() {
+        let model = analyse(Ast::Struct(parse_quote! {
+            #[cuisiner(flags, repr = u8, unknown_bits = preserve)]
+            struct MyFlags {
+                #[cuisiner(bit = 0)]
+                a: bool,
+                #[cuisiner(bit = 7)]
+                b: bool,
+                #[cuisiner(unknown_bits)]
+                other: u8,
+            }
+        }))
+        .unwrap();
+
+        let DeriveModelItem::Flags {
+            flags,
+            unknown_bits,
+            unknown_field,
+            ..
+        } = model.item
+        else {
+            panic!("expected flags derive model item");
+        };
+
+        assert_eq!(
+            flags.iter().map(|(_, bit)| *bit).collect::<Vec<_>>(),
+            [0, 7]
+        );
+        assert_eq!(unknown_bits, UnknownBits::Preserve);
+        assert_eq!(unknown_field.unwrap(), "other");
+    }
+
+    #[test]
+    fn flags_bit_out_of_range() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(flags, repr = u8)]
+                struct MyFlags {
+                    #[cuisiner(bit = 8)]
+                    a: bool,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn flags_duplicate_bit() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(flags, repr = u8)]
+                struct MyFlags {
+                    #[cuisiner(bit = 1)]
+                    a: bool,
+                    #[cuisiner(bit = 1)]
+                    b: bool,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn flags_preserve_without_field() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(flags, repr = u8, unknown_bits = preserve)]
+                struct MyFlags {
+                    #[cuisiner(bit = 1)]
+                    a: bool,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn flags_signed_repr() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(flags, repr = i8)]
+                struct MyFlags {
+                    #[cuisiner(bit = 1)]
+                    a: bool,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn bit_outside_flags() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bit = 1)]
+                    a: bool,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn field_endian() {
+        let model = analyse(Ast::Struct(parse_quote! {
+            #[cuisiner(endian = "big")]
+            struct MyStruct {
+                a: u16,
+                #[cuisiner(endian = little)]
+                b: u16,
+            }
+        }))
+        .unwrap();
+
+        let DeriveModelItem::Struct { fields, .. } = model.item else {
+            panic!("expected struct derive model item");
+        };
+
+        assert_eq!(
+            fields
+                .configs()
+                .iter()
+                .map(|config| config.endian)
+                .collect::<Vec<_>>(),
+            [Some(Endian::Big), Some(Endian::Little)]
+        );
+    }
+
+    #[test]
+    fn unknown_endian() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(endian = "middle")]
+                    a: u16,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn offset_tuple_struct() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct(u8, #[cuisiner(offset = 4)] u32);
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn offset_generic_struct() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct<T> {
+                    a: T,
+                    #[cuisiner(offset = 8)]
+                    b: u32,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn offset_within_bit_field_group() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bits = 4, backing = u8)]
+                    a: u8,
+                    #[cuisiner(bits = 4, offset = 1)]
+                    b: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn c_alignment_tuple_struct() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(c_alignment)]
+                struct MyStruct(u8, u32);
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn offset_and_align() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    a: u8,
+                    #[cuisiner(offset = 4, align = 4)]
+                    b: u32,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn default_without_skip() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(default = 1)]
+                    a: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn skip_with_layout() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(skip, offset = 4)]
+                    a: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn skipped_union_tag() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(skip)]
+                    kind: u8,
+                    #[cuisiner(union_tag = kind)]
+                    shape: Shape,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn with_bit_field() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                struct MyStruct {
+                    #[cuisiner(bits = 4, backing = u8, with = nibble)]
+                    a: u8,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn try_from_without_into() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(try_from = "u16")]
+                struct MyStruct(u32);
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn try_from_into_different_types() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(try_from = "u16", into = "::core::num::NonZero<u16>")]
+                struct MyStruct(u32);
+            }))
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn try_from_into_with_view() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(try_from = "u16", into = "u16", view)]
+                struct MyStruct(u32);
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn try_from_into_enum() {
+        let model = analyse(Ast::Enum(parse_quote! {
+            #[cuisiner(try_from = "u8", into = "u8")]
+            enum MyEnum {
+                First,
+                Second(u8),
+            }
+        }))
+        .unwrap();
+
+        assert!(matches!(model.item, DeriveModelItem::Conversion { .. }));
+    }
+
+    #[test]
+    fn transparent_multiple_fields() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(transparent)]
+                struct MyStruct {
+                    a: u32,
+                    b: u32,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn transparent_skipped_field() {
+        let model = analyse(Ast::Struct(parse_quote! {
+            #[cuisiner(transparent)]
+            struct MyStruct {
+                a: u32,
+                #[cuisiner(skip)]
+                b: u32,
+            }
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            model.item,
+            DeriveModelItem::Struct {
+                transparent: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn transparent_with_view() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(transparent, view)]
+                struct MyStruct(u32);
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn transparent_enum() {
+        assert!(
+            analyse(Ast::Enum(parse_quote! {
+                #[cuisiner(transparent)]
+                enum MyEnum {
+                    First,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn size_generic_struct() {
+        assert!(
+            analyse(Ast::Struct(parse_quote! {
+                #[cuisiner(size = 8)]
+                struct MyStruct<T> {
+                    a: T,
+                }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn enum_size() {
+        assert!(
+            analyse(Ast::Enum(parse_quote! {
+                #[cuisiner(repr = u8, size = 4)]
+                enum MyEnum {
+                    First = 1,
+                }
+            }))
+            .is_err()
+        );
+    }
+
     #[test]
     fn enum_missing_repr() {
         assert!(
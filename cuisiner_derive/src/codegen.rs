@@ -1,8 +1,12 @@
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::{Error, Index, LitInt};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{
+    Error, Expr, Ident, Index, LitInt, Member, Path, Type, Visibility, ext::IdentExt, parse_quote,
+};
 
-use crate::{Fields, Ir, ItemIr, Repr, StructGenerics};
+use crate::{
+    Align, Endian, Fields, Ir, ItemIr, Repr, StructGenerics, UnknownBits, VariantIr, backing_width,
+};
 
 pub fn codegen(ir: Ir) -> Result<TokenStream, Error> {
     let Ir {
@@ -22,13 +26,20 @@ pub fn codegen(ir: Ir) -> Result<TokenStream, Error> {
             raw_derives,
             generics,
             container_assert_layout,
+            view_ident,
+            view_mut_ident,
+            endian,
+            size,
+            c_alignment,
+            transparent,
         } => {
             let StructGenerics {
                 base: base_generics,
                 raw: raw_generics,
                 b_ident: b_generic_ident,
                 b_generic,
-            } = &generics;
+                ..
+            } = generics.as_ref();
             let (impl_generics, ty_generics, where_clause) = base_generics.split_for_impl();
             let (_, raw_ty_generics, raw_where_clause) = raw_generics.split_for_impl();
 
@@ -36,71 +47,212 @@ pub fn codegen(ir: Ir) -> Result<TokenStream, Error> {
                 quote! { #[#crate_name::assert_layout(#(#metas,)*)] }
             });
 
-            let (field_definitions, from_raws, to_raws) = match fields {
-                Fields::Named(fields) => {
-                    let fields_len = fields.len();
-                    let (names, tys, assertions) = fields.into_iter().fold(
-                        (
-                            Vec::with_capacity(fields_len),
-                            Vec::with_capacity(fields_len),
-                            Vec::with_capacity(fields_len),
-                        ),
-                        |(mut names, mut tys, mut assertions), (name, ty, assertion)| {
-                            names.push(name);
-                            tys.push(ty);
-                            assertions.push(assertion.map(|metas| {
-                                quote! { #[assert_layout(#(#metas,)*)] }
-                            }));
-
-                            (names, tys, assertions)
-                        },
-                    );
+            let fields = StructFields::from(fields);
+            if transparent {
+                return Ok(transparent_codegen(
+                    &crate_name,
+                    &base_ident,
+                    &generics,
+                    &fields,
+                    endian,
+                ));
+            }
 
-                    (
-                        quote! {
-                            { #(#assertions #names: <#tys as #crate_name::Cuisiner>::Raw::<#b_generic_ident>),* }
-                        },
-                        quote! {
-                            { #(#names: <#tys as #crate_name::Cuisiner>::try_from_raw::<#b_generic_ident>(raw.#names)?),* }
-                        },
-                        quote! {
-                            { #(#names: <#tys as #crate_name::Cuisiner>::try_to_raw::<#b_generic_ident>(self.#names)?),* }
-                        },
-                    )
+            let StructFields { members, tys, .. } = &fields;
+            let field_errors = fields.error_mappers(
+                &crate_name,
+                &base_ident.unraw().to_string(),
+                &quote!(#raw_ident #raw_ty_generics),
+                None,
+            );
+            let field_definitions =
+                fields.raw_definitions(&crate_name, b_generic_ident, size.as_deref(), c_alignment);
+            let orders = fields.byte_orders(&crate_name, b_generic_ident);
+            let marker = fields.marker_member();
+
+            let bindings = (0..members.len())
+                .map(|i| format_ident!("___cuisiner_field_{i}"))
+                .collect::<Vec<_>>();
+            let mut from_raw = Vec::with_capacity(members.len());
+            let mut to_raw = Vec::with_capacity(members.len());
+            let mut validate = Vec::with_capacity(members.len());
+            // Backing member, type, and packing statements for each group of bit fields.
+            let mut bit_groups = Vec::new();
+            // Conversion functions for each field, and whether they are provided by a module.
+            let conversions = fields
+                .conversions_from_raw(&crate_name, &orders)
+                .into_iter()
+                .zip(fields.conversions_to_raw(&crate_name, &orders))
+                .zip(&fields.withs);
+            for (
+                ((((((member, raw_member), ty), field_errors), union_tag), bit_field), order),
+                conversion,
+            ) in members
+                .iter()
+                .zip(&fields.raw_members)
+                .zip(tys)
+                .zip(&field_errors)
+                .zip(&fields.union_tags)
+                .zip(&fields.bit_fields)
+                .zip(&orders)
+                .zip(conversions)
+            {
+                if let Some(BitFieldIr {
+                    raw_member,
+                    backing,
+                    bits,
+                    shift,
+                    first,
+                }) = bit_field
+                {
+                    let unpack = quote! {
+                        <#backing as #crate_name::Cuisiner>::try_from_raw::<#order>(
+                            #crate_name::__private::copy_raw(&raw.#raw_member),
+                        )
+                        .and_then(|backing| {
+                            <#ty as #crate_name::BitField>::from_bits(
+                                #crate_name::__private::unpack_bits(::core::convert::From::from(backing), #bits, #shift),
+                            )
+                        })
+                    };
+                    from_raw.push(quote!(#unpack.map_err(#field_errors)?));
+                    validate.push(quote! {
+                        if let ::core::result::Result::Err(e) = #unpack {
+                            errors.push((#field_errors)(e));
+                        }
+                    });
+
+                    let pack = quote! {
+                        #crate_name::__private::pack_bits(
+                            &mut backing,
+                            <#ty as #crate_name::BitField>::to_bits(&self.#member),
+                            #bits,
+                            #shift,
+                        )
+                        .map_err(#field_errors)?;
+                    };
+                    if *first {
+                        bit_groups.push((raw_member, backing, order, vec![pack]));
+                    } else {
+                        let (_, _, _, packs) =
+                            bit_groups.last_mut().expect("bit field is within a group");
+                        packs.push(pack);
+                    }
+
+                    continue;
                 }
-                Fields::Unnamed(fields) => {
-                    let fields_len = fields.len();
-                    let (names, tys, assertions) = fields.iter().enumerate().fold(
-                        (
-                            Vec::with_capacity(fields_len),
-                            Vec::with_capacity(fields_len),
-                            Vec::with_capacity(fields_len),
-                        ),
-                        |(mut names, mut tys, mut assertions), (name, (ty, assertion))| {
-                            names.push(Index::from(name));
-                            tys.push(ty);
-                            assertions.push(assertion.as_ref().map(|metas| {
-                                quote! { #[assert_layout(#(#metas,)*)] }
-                            }));
-
-                            (names, tys, assertions)
-                        },
-                    );
 
-                    (
-                        quote! {
-                            (#(#assertions #tys),*);
-                        },
-                        quote! {
-                            (#(<#tys as #crate_name::Cuisiner>::try_from_raw(raw.#names)?),*);
+                let Some((tag_index, tag_member)) = union_tag else {
+                    let ((from_raw_fn, to_raw_fn), with) = conversion;
+                    from_raw.push(quote! {
+                        #from_raw_fn(raw.#raw_member).map_err(#field_errors)?
+                    });
+                    to_raw.push(quote! {
+                        #raw_member: #to_raw_fn(&self.#member).map_err(#field_errors)?
+                    });
+                    // Modules provided with `with` can only report the first problem.
+                    validate.push(match with {
+                        Some(_) => quote! {
+                            if let ::core::result::Result::Err(e) = #from_raw_fn(#crate_name::__private::copy_raw(&raw.#raw_member)) {
+                                errors.push((#field_errors)(e));
+                            }
                         },
-                        quote! {
-                            (#(<#tys as #crate_name::Cuisiner>::try_to_raw(self.#names)?),*);
+                        None => quote! {
+                            let mut field_errors = ::std::vec::Vec::new();
+                            <#ty as #crate_name::Cuisiner>::validate_raw::<#order>(&raw.#raw_member, &mut field_errors);
+                            errors.extend(field_errors.into_iter().map(#field_errors));
                         },
+                    });
+                    continue;
+                };
+
+                // Union fields are selected by the value of the tag field, which has already been
+                // converted.
+                let tag_binding = &bindings[*tag_index];
+                let tag_ty = &tys[*tag_index];
+                let tag_order = &orders[*tag_index];
+                from_raw.push(quote! {
+                    #crate_name::__private::union_tag::<#ty, #tag_ty>(&#tag_binding)
+                        .and_then(|tag| {
+                            <#ty as #crate_name::CuisinerUnion>::try_from_raw::<#order>(tag, raw.#raw_member)
+                        })
+                        .map_err(#field_errors)?
+                });
+                to_raw.push(quote! {
+                    #raw_member: #crate_name::__private::union_to_raw::<#ty, #order, #tag_ty>(
+                        &self.#member,
+                        &self.#tag_member,
                     )
+                    .map_err(#field_errors)?
+                });
+                // If the tag itself is invalid, it will already have been reported.
+                validate.push(quote! {
+                    if let ::core::result::Result::Ok(tag) = <#tag_ty as #crate_name::Cuisiner>::try_from_raw::<#tag_order>(
+                        #crate_name::__private::copy_raw(&raw.#tag_member),
+                    ) {
+                        let member = #crate_name::__private::union_tag::<#ty, #tag_ty>(&tag).and_then(|tag| {
+                            <#ty as #crate_name::CuisinerUnion>::try_from_raw::<#order>(
+                                tag,
+                                #crate_name::__private::copy_raw(&raw.#raw_member),
+                            )
+                        });
+                        if let ::core::result::Result::Err(e) = member {
+                            errors.push((#field_errors)(e));
+                        }
+                    }
+                });
+            }
+
+            to_raw.extend(bit_groups.into_iter().map(|(raw_member, backing, order, packs)| {
+                quote! {
+                    #raw_member: {
+                        let mut backing = 0;
+                        #(#packs)*
+                        <#backing as #crate_name::Cuisiner>::try_to_raw::<#order>(backing as #backing)?
+                    }
                 }
-                Fields::Unit => (quote!(;), quote!(;), quote!(;)),
-            };
+            }));
+            to_raw.push(quote!(#marker: ::core::marker::PhantomData));
+            to_raw.extend(fields.paddings.iter().flatten().map(
+                |(_, padding)| quote!(#padding: #crate_name::zerocopy::FromZeros::new_zeroed()),
+            ));
+            if size.is_some() || c_alignment {
+                let tail_padding = fields.tail_padding_member();
+                to_raw.push(quote!(#tail_padding: #crate_name::zerocopy::FromZeros::new_zeroed()));
+            }
+
+            let views = [(view_ident, false), (view_mut_ident, true)]
+                .into_iter()
+                .filter_map(|(view_ident, mutable)| Some((view_ident?, mutable)))
+                .map(|(view_ident, mutable)| {
+                    view_codegen(
+                        &crate_name,
+                        &base_ident,
+                        &visibility,
+                        &raw_ident,
+                        &view_ident,
+                        mutable,
+                        &generics,
+                        &fields,
+                    )
+                });
+
+            let (skipped_members, skipped_values) = fields.skipped_defaults();
+
+            let container_align = c_alignment.then(|| {
+                let align = fields.container_alignment(&crate_name);
+                quote!(const ALIGN: usize = #align;)
+            });
+
+            let fixed_endian = endian.map(|endian| {
+                fixed_endian_codegen(
+                    &crate_name,
+                    &base_ident,
+                    &quote!(#impl_generics),
+                    &quote!(#ty_generics #where_clause),
+                    endian,
+                )
+            });
 
             Ok(quote! {
                 #[derive(#(#raw_derives),*)]
@@ -113,33 +265,91 @@ pub fn codegen(ir: Ir) -> Result<TokenStream, Error> {
                 #[automatically_derived]
                 impl #impl_generics #crate_name::Cuisiner for #base_ident #ty_generics #where_clause {
                     type Raw<#b_generic> = #raw_ident #raw_ty_generics;
+                    #container_align
 
                     fn try_from_raw<#b_generic>(raw: Self::Raw<#b_generic_ident>) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
-                        Ok(Self #from_raws)
+                        #(let #bindings = #from_raw;)*
+
+                        Ok(Self {
+                            #(#members: #bindings,)*
+                            #(#skipped_members: #skipped_values,)*
+                        })
                     }
 
                     fn try_to_raw<#b_generic>(self) -> ::core::result::Result<Self::Raw<#b_generic_ident>, #crate_name::CuisinerError> {
-                        Ok(Self::Raw #to_raws)
+                        <Self as #crate_name::Cuisiner>::try_to_raw_ref::<#b_generic_ident>(&self)
+                    }
+
+                    fn try_to_raw_ref<#b_generic>(&self) -> ::core::result::Result<Self::Raw<#b_generic_ident>, #crate_name::CuisinerError> {
+                        Ok(Self::Raw {
+                            #(#to_raw,)*
+                        })
+                    }
+
+                    fn validate_raw<#b_generic>(raw: &Self::Raw<#b_generic_ident>, errors: &mut ::std::vec::Vec<#crate_name::CuisinerError>) {
+                        #({ #validate })*
                     }
                 }
+
+                #(#views)*
+                #fixed_endian
             })
         }
-        ItemIr::Enum { variants, repr } => {
-            let repr_ty = match repr {
-                Repr::U8 => quote!(u8),
-                Repr::U16 => quote!(u16),
-                Repr::U32 => quote!(u32),
-                Repr::U64 => quote!(u64),
-                Repr::U128 => quote!(u128),
-                Repr::Usize => quote!(usize),
-                Repr::I8 => quote!(i8),
-                Repr::I16 => quote!(i16),
-                Repr::I32 => quote!(i32),
-                Repr::I64 => quote!(i64),
-                Repr::I128 => quote!(i128),
-                Repr::Isize => quote!(isize),
+        ItemIr::Enum {
+            variants,
+            repr,
+            other,
+            union,
+            endian,
+            raw_ident,
+        } => {
+            let repr_ty = repr_ty(&repr);
+            let tag_order = byte_order(&crate_name, endian, &format_ident!("B"));
+            let raw_repr = quote!(<#repr_ty as #crate_name::Cuisiner>::Raw::<#tag_order>);
+
+            let type_name = base_ident.unraw().to_string();
+            let invalid_discriminant = quote! {
+                #crate_name::CuisinerError::InvalidDiscriminant {
+                    type_name: #type_name,
+                    value: ::core::convert::From::from(n),
+                }
             };
-            let raw_repr = quote!(<#repr_ty as #crate_name::Cuisiner>::Raw::<B>);
+
+            if union {
+                return Ok(union_codegen(
+                    &crate_name,
+                    &base_ident,
+                    &visibility,
+                    &zerocopy_crate,
+                    &repr_ty,
+                    &invalid_discriminant,
+                    variants,
+                ));
+            }
+
+            let fixed_endian = endian.map(|endian| {
+                fixed_endian_codegen(&crate_name, &base_ident, &quote!(), &quote!(), endian)
+            });
+
+            if let Some(raw_ident) = raw_ident {
+                let tagged_enum = tagged_enum_codegen(
+                    &crate_name,
+                    &base_ident,
+                    &visibility,
+                    &zerocopy_crate,
+                    &repr_ty,
+                    &tag_order,
+                    &raw_ident,
+                    &invalid_discriminant,
+                    variants,
+                );
+
+                return Ok(quote! {
+                    #tagged_enum
+                    #fixed_endian
+                });
+            }
+
             let (raw_value, raw_constructor) = match repr {
                 Repr::U8 | Repr::I8 => (quote!(raw), None),
                 _ => (quote!(raw.get()), Some(quote!(#raw_repr::new))),
@@ -147,38 +357,1180 @@ pub fn codegen(ir: Ir) -> Result<TokenStream, Error> {
 
             let (variants, discriminants): (Vec<_>, Vec<_>) = variants
                 .into_iter()
-                .map(|(variant, discriminant)| {
-                    (
-                        variant,
-                        // To prevent `usize` being included in the value, turn it into a literal
-                        // value.
-                        LitInt::new(&discriminant.to_string(), Span::call_site()),
-                    )
-                })
+                .map(|variant| (variant.ident, discriminant_literal(variant.discriminant)))
                 .unzip();
 
-            let invalid_discriminant_message =
-                format!("invalid discriminant for {base_ident}: {{}}");
+            // Unknown discriminants are either stored in the `other` variant, or rejected.
+            let (unknown_from_raw, unknown_to_raw) = match other {
+                Some(other) => (
+                    quote!(n => ::core::result::Result::Ok(Self::#other(n)),),
+                    Some(quote!(Self::#other(n) => *n,)),
+                ),
+                None => (
+                    quote!(n => ::core::result::Result::Err(#invalid_discriminant),),
+                    None,
+                ),
+            };
 
             Ok(quote! {
                 #[automatically_derived]
                 impl #crate_name::Cuisiner for #base_ident {
                     type Raw<B: #crate_name::zerocopy::ByteOrder> = #raw_repr;
+                    const ALIGN: usize = <#repr_ty as #crate_name::Cuisiner>::ALIGN;
 
                     fn try_from_raw<B: #crate_name::zerocopy::ByteOrder>(raw: Self::Raw<B>) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
                         match #raw_value {
                             #(#discriminants => ::core::result::Result::Ok(Self::#variants),)*
-                            n => ::core::result::Result::Err(#crate_name::CuisinerError::Validation(::std::format!(#invalid_discriminant_message, n))),
+                            #unknown_from_raw
                         }
                     }
 
                     fn try_to_raw<B: #crate_name::zerocopy::ByteOrder>(self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                        <Self as #crate_name::Cuisiner>::try_to_raw_ref::<B>(&self)
+                    }
+
+                    fn try_to_raw_ref<B: #crate_name::zerocopy::ByteOrder>(&self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
                         ::core::result::Result::Ok(#raw_constructor(match self {
                             #(Self::#variants => #discriminants,)*
+                            #unknown_to_raw
                         }))
                     }
                 }
+
+                #fixed_endian
             })
         }
+        ItemIr::Flags {
+            flags,
+            repr,
+            unknown_bits,
+            unknown_field,
+            endian,
+        } => {
+            let repr_ty = repr_ty(&repr);
+            let order = byte_order(&crate_name, endian, &format_ident!("B"));
+            let fixed_endian = endian.map(|endian| {
+                fixed_endian_codegen(&crate_name, &base_ident, &quote!(), &quote!(), endian)
+            });
+            let type_name = base_ident.unraw().to_string();
+            let (flag_idents, flag_bits): (Vec<_>, Vec<_>) = flags.into_iter().unzip();
+            let known = quote!((0 #(| (1 << #flag_bits))*));
+
+            let (unknown_from_raw, unknown_field_from_raw, unknown_to_raw) = match (
+                unknown_bits,
+                unknown_field,
+            ) {
+                (UnknownBits::Reject, _) => (
+                    Some(quote! {
+                        let unknown = bits & !#known;
+                        if unknown != 0 {
+                            return ::core::result::Result::Err(#crate_name::CuisinerError::UnknownBits {
+                                type_name: #type_name,
+                                bits: ::core::convert::From::from(unknown),
+                            });
+                        }
+                    }),
+                    None,
+                    None,
+                ),
+                (UnknownBits::Drop, _) => (None, None, None),
+                (UnknownBits::Preserve, unknown_field) => {
+                    let unknown_field =
+                        unknown_field.expect("preserved unknown bits require a field");
+                    (
+                        Some(quote!(let unknown = bits & !#known;)),
+                        Some(quote!(#unknown_field: unknown,)),
+                        // Only undefined bits are written, so that the flags can't be overridden.
+                        Some(quote!(bits |= self.#unknown_field & !#known;)),
+                    )
+                }
+            };
+
+            Ok(quote! {
+                #[automatically_derived]
+                impl #crate_name::Cuisiner for #base_ident {
+                    type Raw<B: #crate_name::zerocopy::ByteOrder> = <#repr_ty as #crate_name::Cuisiner>::Raw::<#order>;
+                    const ALIGN: usize = <#repr_ty as #crate_name::Cuisiner>::ALIGN;
+
+                    fn try_from_raw<B: #crate_name::zerocopy::ByteOrder>(raw: Self::Raw<B>) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                        let bits = <#repr_ty as #crate_name::Cuisiner>::try_from_raw::<#order>(raw)?;
+                        #unknown_from_raw
+
+                        ::core::result::Result::Ok(Self {
+                            #(#flag_idents: bits & (1 << #flag_bits) != 0,)*
+                            #unknown_field_from_raw
+                        })
+                    }
+
+                    fn try_to_raw<B: #crate_name::zerocopy::ByteOrder>(self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                        <Self as #crate_name::Cuisiner>::try_to_raw_ref::<B>(&self)
+                    }
+
+                    fn try_to_raw_ref<B: #crate_name::zerocopy::ByteOrder>(&self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                        let mut bits: #repr_ty = 0;
+                        #(
+                            if self.#flag_idents {
+                                bits |= 1 << #flag_bits;
+                            }
+                        )*
+                        #unknown_to_raw
+
+                        <#repr_ty as #crate_name::Cuisiner>::try_to_raw::<#order>(bits)
+                    }
+                }
+
+                #fixed_endian
+            })
+        }
+        ItemIr::Conversion {
+            try_from,
+            into,
+            endian,
+        } => {
+            let order = byte_order(&crate_name, endian, &format_ident!("B"));
+            let fixed_endian = endian.map(|endian| {
+                fixed_endian_codegen(&crate_name, &base_ident, &quote!(), &quote!(), endian)
+            });
+
+            // Converting to the raw value by reference clones the value before converting it into
+            // `into`, whose raw representation must be that of `try_from`.
+            let into_span = syn::spanned::Spanned::span(&into);
+            let clone_bound = quote_spanned!(into_span=> #base_ident: ::core::clone::Clone);
+            let into_raw = quote_spanned! {into_span=>
+                #crate_name::__private::into_raw::<#try_from, #into, #order>
+            };
+
+            Ok(quote! {
+                #[automatically_derived]
+                impl #crate_name::Cuisiner for #base_ident
+                where
+                    #clone_bound,
+                {
+                    type Raw<B: #crate_name::zerocopy::ByteOrder> = <#try_from as #crate_name::Cuisiner>::Raw::<#order>;
+                    const ALIGN: usize = <#try_from as #crate_name::Cuisiner>::ALIGN;
+
+                    fn try_from_raw<B: #crate_name::zerocopy::ByteOrder>(raw: Self::Raw<B>) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                        let value = <#try_from as #crate_name::Cuisiner>::try_from_raw::<#order>(raw)?;
+                        <Self as ::core::convert::TryFrom<#try_from>>::try_from(value)
+                            .map_err(#crate_name::CuisinerError::custom)
+                    }
+
+                    fn try_to_raw<B: #crate_name::zerocopy::ByteOrder>(self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                        let value: #into = ::core::convert::Into::into(self);
+                        <#into as #crate_name::Cuisiner>::try_to_raw::<#order>(value).map(#into_raw)
+                    }
+
+                    fn try_to_raw_ref<B: #crate_name::zerocopy::ByteOrder>(&self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                        let value: #into = ::core::convert::Into::into(::core::clone::Clone::clone(self));
+                        <#into as #crate_name::Cuisiner>::try_to_raw::<#order>(value).map(#into_raw)
+                    }
+                }
+
+                #fixed_endian
+            })
+        }
+    }
+}
+
+/// Byte order to convert with, which is `b_ident` unless pinned with `endian`.
+fn byte_order(crate_name: &Path, endian: Option<Endian>, b_ident: &impl ToTokens) -> TokenStream {
+    match endian {
+        Some(endian) => endian.byte_order(crate_name),
+        None => quote!(#b_ident),
+    }
+}
+
+/// Implementation for a `transparent` struct, which reuses the raw representation of its only
+/// field rather than generating a raw struct.
+fn transparent_codegen(
+    crate_name: &Path,
+    base_ident: &Ident,
+    generics: &StructGenerics,
+    fields: &StructFields,
+    endian: Option<Endian>,
+) -> TokenStream {
+    let StructGenerics {
+        base: base_generics,
+        b_ident: b_generic_ident,
+        b_generic,
+        ..
+    } = generics;
+    let (impl_generics, ty_generics, where_clause) = base_generics.split_for_impl();
+
+    let member = &fields.members[0];
+    let ty = &fields.tys[0];
+    let order = &fields.byte_orders(crate_name, b_generic_ident)[0];
+    let (skipped_members, skipped_values) = fields.skipped_defaults();
+
+    let fixed_endian = endian.map(|endian| {
+        fixed_endian_codegen(
+            crate_name,
+            base_ident,
+            &quote!(#impl_generics),
+            &quote!(#ty_generics #where_clause),
+            endian,
+        )
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #crate_name::Cuisiner for #base_ident #ty_generics #where_clause {
+            type Raw<#b_generic> = <#ty as #crate_name::Cuisiner>::Raw<#order>;
+            const ALIGN: usize = <#ty as #crate_name::Cuisiner>::ALIGN;
+
+            fn try_from_raw<#b_generic>(raw: Self::Raw<#b_generic_ident>) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                Ok(Self {
+                    #member: <#ty as #crate_name::Cuisiner>::try_from_raw::<#order>(raw)?,
+                    #(#skipped_members: #skipped_values,)*
+                })
+            }
+
+            fn try_to_raw<#b_generic>(self) -> ::core::result::Result<Self::Raw<#b_generic_ident>, #crate_name::CuisinerError> {
+                <#ty as #crate_name::Cuisiner>::try_to_raw::<#order>(self.#member)
+            }
+
+            fn try_to_raw_ref<#b_generic>(&self) -> ::core::result::Result<Self::Raw<#b_generic_ident>, #crate_name::CuisinerError> {
+                <#ty as #crate_name::Cuisiner>::try_to_raw_ref::<#order>(&self.#member)
+            }
+
+            fn validate_raw<#b_generic>(raw: &Self::Raw<#b_generic_ident>, errors: &mut ::std::vec::Vec<#crate_name::CuisinerError>) {
+                <#ty as #crate_name::Cuisiner>::validate_raw::<#order>(raw, errors)
+            }
+        }
+
+        #fixed_endian
+    }
+}
+
+/// Inherent conversion methods for a container with a pinned byte order, which shadow the trait
+/// methods so that a byte order doesn't need to be provided.
+fn fixed_endian_codegen(
+    crate_name: &Path,
+    base_ident: &Ident,
+    impl_generics: &TokenStream,
+    ty_generics: &TokenStream,
+    endian: Endian,
+) -> TokenStream {
+    let order = endian.byte_order(crate_name);
+
+    quote! {
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #impl_generics #base_ident #ty_generics {
+            /// Read the provided bytes in the container's byte order. Any trailing bytes are
+            /// ignored.
+            pub fn from_bytes(bytes: &[u8]) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                <Self as #crate_name::Cuisiner>::from_bytes::<#order>(bytes)
+            }
+
+            /// Convert the value to it's raw representation in the container's byte order.
+            pub fn to_bytes(&self) -> ::core::result::Result<::std::vec::Vec<u8>, #crate_name::CuisinerError> {
+                <Self as #crate_name::Cuisiner>::to_bytes::<#order>(self)
+            }
+
+            /// Read exactly enough bytes for the raw representation from the reader in the
+            /// container's byte order, and attempt to parse out the type.
+            pub fn read_from<R: ::std::io::Read>(reader: R) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                <Self as #crate_name::Cuisiner>::read_from::<#order, R>(reader)
+            }
+        }
+    }
+}
+
+/// Primitive type for an integer representation.
+fn repr_ty(repr: &Repr) -> TokenStream {
+    match repr {
+        Repr::U8 => quote!(u8),
+        Repr::U16 => quote!(u16),
+        Repr::U32 => quote!(u32),
+        Repr::U64 => quote!(u64),
+        Repr::U128 => quote!(u128),
+        Repr::Usize => quote!(usize),
+        Repr::I8 => quote!(i8),
+        Repr::I16 => quote!(i16),
+        Repr::I32 => quote!(i32),
+        Repr::I64 => quote!(i64),
+        Repr::I128 => quote!(i128),
+        Repr::Isize => quote!(isize),
+    }
+}
+
+/// To prevent `usize` being included in the value, turn the discriminant into an unsuffixed
+/// literal.
+fn discriminant_literal(discriminant: usize) -> LitInt {
+    LitInt::new(&discriminant.to_string(), Span::call_site())
+}
+
+/// Conversions for each variant of an enum whose variants share a body of bytes, with each
+/// payload written at the start of the body and the remainder zeroed.
+#[derive(Default)]
+struct VariantsCodegen {
+    /// Raw struct definition for each variant carrying data.
+    payload_definitions: Vec<TokenStream>,
+    /// Size of each raw payload, used to size the body.
+    payload_sizes: Vec<TokenStream>,
+    /// Alignment of each payload, being the largest alignment of its fields.
+    payload_alignments: Vec<TokenStream>,
+    /// Arms converting the body to a variant, matching on the discriminant.
+    from_raw_arms: Vec<TokenStream>,
+    /// Arms writing each variant into the body.
+    to_raw_arms: Vec<TokenStream>,
+    /// Arms producing the discriminant of each variant.
+    tag_arms: Vec<TokenStream>,
+    /// Arms validating the body, matching on the discriminant.
+    validate_arms: Vec<TokenStream>,
+}
+
+impl VariantsCodegen {
+    /// Generate the conversions for each variant. `body` is the expression for the body within the
+    /// raw value, and `body_offset` is the offset of the body within the raw value, if non-zero.
+    fn new(
+        crate_name: &Path,
+        base_ident: &Ident,
+        visibility: &Visibility,
+        zerocopy_crate: &str,
+        body: &TokenStream,
+        body_offset: Option<&TokenStream>,
+        variants: Vec<VariantIr>,
+    ) -> Self {
+        let b_ident = format_ident!("B");
+        let mut out = Self::default();
+
+        for variant in variants {
+            let ident = variant.ident;
+            let discriminant = discriminant_literal(variant.discriminant);
+            out.tag_arms
+                .push(quote!(Self::#ident { .. } => #discriminant,));
+
+            let Some(payload_ident) = variant.raw_ident else {
+                out.from_raw_arms
+                    .push(quote!(#discriminant => ::core::result::Result::Ok(Self::#ident {}),));
+                out.to_raw_arms.push(quote!(Self::#ident { .. } => {}));
+                out.validate_arms.push(quote!(#discriminant => {}));
+                continue;
+            };
+
+            let fields = StructFields::from(variant.fields);
+            let StructFields { members, tys, .. } = &fields;
+            let payload_ty = quote!(#payload_ident<B>);
+            let field_errors = fields.error_mappers(
+                crate_name,
+                &format!("{}::{}", base_ident.unraw(), ident.unraw()),
+                &payload_ty,
+                body_offset,
+            );
+            let bindings = (0..members.len())
+                .map(|i| format_ident!("___cuisiner_field_{i}"))
+                .collect::<Vec<_>>();
+
+            let payload_fields = fields.raw_definitions(crate_name, &b_ident, None, false);
+            let orders = fields.byte_orders(crate_name, &b_ident);
+            let marker = fields.marker_member();
+            out.payload_definitions.push(quote! {
+                #[derive(
+                    #crate_name::zerocopy::FromBytes,
+                    #crate_name::zerocopy::IntoBytes,
+                    #crate_name::zerocopy::Immutable,
+                    #crate_name::zerocopy::KnownLayout,
+                    #crate_name::zerocopy::Unaligned,
+                )]
+                #[repr(C)]
+                #[zerocopy(crate = #zerocopy_crate)]
+                #[automatically_derived]
+                #visibility struct #payload_ident<B: #crate_name::zerocopy::ByteOrder> #payload_fields
+            });
+            out.payload_sizes
+                .push(quote!(::core::mem::size_of::<#payload_ident<#crate_name::BigEndian>>()));
+            out.payload_alignments
+                .push(fields.container_alignment(crate_name));
+
+            out.from_raw_arms.push(quote! {
+                #discriminant => {
+                    let payload: #payload_ty = #crate_name::__private::read_payload(&#body);
+                    ::core::result::Result::Ok(Self::#ident {
+                        #(#members: <#tys as #crate_name::Cuisiner>::try_from_raw::<#orders>(payload.#members).map_err(#field_errors)?,)*
+                    })
+                }
+            });
+            out.to_raw_arms.push(quote! {
+                Self::#ident { #(#members: #bindings),* } => {
+                    let payload: #payload_ty = #payload_ident {
+                        #(#members: <#tys as #crate_name::Cuisiner>::try_to_raw_ref::<#orders>(#bindings).map_err(#field_errors)?,)*
+                        #marker: ::core::marker::PhantomData,
+                    };
+                    #crate_name::__private::write_payload(&payload, &mut #body);
+                }
+            });
+            out.validate_arms.push(quote! {
+                #discriminant => {
+                    let payload: #payload_ty = #crate_name::__private::read_payload(&#body);
+                    #({
+                        let mut field_errors = ::std::vec::Vec::new();
+                        <#tys as #crate_name::Cuisiner>::validate_raw::<#orders>(&payload.#members, &mut field_errors);
+                        errors.extend(field_errors.into_iter().map(#field_errors));
+                    })*
+                }
+            });
+        }
+
+        out
+    }
+}
+
+/// Generate the implementation for an enum with data-carrying variants. The raw representation is
+/// the tag followed by a body large enough for the raw payload of any variant.
+#[allow(clippy::too_many_arguments)]
+fn tagged_enum_codegen(
+    crate_name: &Path,
+    base_ident: &Ident,
+    visibility: &Visibility,
+    zerocopy_crate: &str,
+    repr_ty: &TokenStream,
+    tag_order: &TokenStream,
+    raw_ident: &Ident,
+    invalid_discriminant: &TokenStream,
+    variants: Vec<VariantIr>,
+) -> TokenStream {
+    let raw_ty = quote!(#raw_ident<B>);
+    let VariantsCodegen {
+        payload_definitions,
+        payload_sizes,
+        payload_alignments,
+        from_raw_arms,
+        to_raw_arms,
+        tag_arms,
+        validate_arms,
+    } = VariantsCodegen::new(
+        crate_name,
+        base_ident,
+        visibility,
+        zerocopy_crate,
+        &quote!(raw.body),
+        Some(&quote!(::core::mem::offset_of!(#raw_ty, body))),
+        variants,
+    );
+
+    quote! {
+        #(#payload_definitions)*
+
+        #[derive(
+            #crate_name::zerocopy::FromBytes,
+            #crate_name::zerocopy::IntoBytes,
+            #crate_name::zerocopy::Immutable,
+            #crate_name::zerocopy::KnownLayout,
+            #crate_name::zerocopy::Unaligned,
+        )]
+        #[repr(C)]
+        #[zerocopy(crate = #zerocopy_crate)]
+        #[automatically_derived]
+        #visibility struct #raw_ident<B: #crate_name::zerocopy::ByteOrder> {
+            tag: <#repr_ty as #crate_name::Cuisiner>::Raw<#tag_order>,
+            body: [u8; #crate_name::__private::max_size(&[#(#payload_sizes),*])],
+            ___cuisiner_byte_order: ::core::marker::PhantomData<B>,
+        }
+
+        #[automatically_derived]
+        impl #crate_name::Cuisiner for #base_ident {
+            type Raw<B: #crate_name::zerocopy::ByteOrder> = #raw_ty;
+
+            const ALIGN: usize = #crate_name::__private::max_size(&[
+                <#repr_ty as #crate_name::Cuisiner>::ALIGN,
+                #(#payload_alignments),*
+            ]);
+
+            fn try_from_raw<B: #crate_name::zerocopy::ByteOrder>(raw: Self::Raw<B>) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                match <#repr_ty as #crate_name::Cuisiner>::try_from_raw::<#tag_order>(raw.tag)? {
+                    #(#from_raw_arms)*
+                    n => ::core::result::Result::Err(#invalid_discriminant),
+                }
+            }
+
+            fn try_to_raw<B: #crate_name::zerocopy::ByteOrder>(self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                <Self as #crate_name::Cuisiner>::try_to_raw_ref::<B>(&self)
+            }
+
+            fn try_to_raw_ref<B: #crate_name::zerocopy::ByteOrder>(&self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                let mut raw: Self::Raw<B> = #crate_name::zerocopy::FromZeros::new_zeroed();
+                match self {
+                    #(#to_raw_arms)*
+                }
+
+                let tag: #repr_ty = match self {
+                    #(#tag_arms)*
+                };
+                raw.tag = <#repr_ty as #crate_name::Cuisiner>::try_to_raw::<#tag_order>(tag)?;
+
+                ::core::result::Result::Ok(raw)
+            }
+
+            fn validate_raw<B: #crate_name::zerocopy::ByteOrder>(raw: &Self::Raw<B>, errors: &mut ::std::vec::Vec<#crate_name::CuisinerError>) {
+                let tag = match <#repr_ty as #crate_name::Cuisiner>::try_from_raw::<#tag_order>(#crate_name::__private::copy_raw(&raw.tag)) {
+                    ::core::result::Result::Ok(tag) => tag,
+                    ::core::result::Result::Err(e) => {
+                        errors.push(e);
+                        return;
+                    }
+                };
+
+                match tag {
+                    #(#validate_arms)*
+                    n => errors.push(#invalid_discriminant),
+                }
+            }
+        }
+    }
+}
+
+/// Generate the `CuisinerUnion` implementation for an enum. The raw representation is only the
+/// bytes of the largest member, with the tag provided externally.
+fn union_codegen(
+    crate_name: &Path,
+    base_ident: &Ident,
+    visibility: &Visibility,
+    zerocopy_crate: &str,
+    repr_ty: &TokenStream,
+    invalid_discriminant: &TokenStream,
+    variants: Vec<VariantIr>,
+) -> TokenStream {
+    let VariantsCodegen {
+        payload_definitions,
+        payload_sizes,
+        payload_alignments,
+        from_raw_arms,
+        to_raw_arms,
+        tag_arms,
+        ..
+    } = VariantsCodegen::new(
+        crate_name,
+        base_ident,
+        visibility,
+        zerocopy_crate,
+        &quote!(raw),
+        None,
+        variants,
+    );
+
+    quote! {
+        #(#payload_definitions)*
+
+        #[automatically_derived]
+        impl #crate_name::CuisinerUnion for #base_ident {
+            type Raw<B: #crate_name::zerocopy::ByteOrder> =
+                [u8; #crate_name::__private::max_size(&[#(#payload_sizes),*])];
+            type Tag = #repr_ty;
+
+            const ALIGN: usize = #crate_name::__private::max_size(&[1, #(#payload_alignments),*]);
+
+            fn try_from_raw<B: #crate_name::zerocopy::ByteOrder>(tag: Self::Tag, raw: Self::Raw<B>) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                match tag {
+                    #(#from_raw_arms)*
+                    n => ::core::result::Result::Err(#invalid_discriminant),
+                }
+            }
+
+            fn tag(&self) -> Self::Tag {
+                match self {
+                    #(#tag_arms)*
+                }
+            }
+
+            fn try_to_raw_ref<B: #crate_name::zerocopy::ByteOrder>(&self) -> ::core::result::Result<Self::Raw<B>, #crate_name::CuisinerError> {
+                let mut raw: Self::Raw<B> = #crate_name::zerocopy::FromZeros::new_zeroed();
+                match self {
+                    #(#to_raw_arms)*
+                }
+
+                ::core::result::Result::Ok(raw)
+            }
+        }
+    }
+}
+
+/// Shape of the fields of a struct.
+enum FieldsKind {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+/// Fields of a struct, split into their components for use in quoting.
+struct StructFields {
+    kind: FieldsKind,
+    /// Member used to access each field, from both the base and raw struct.
+    members: Vec<Member>,
+    /// Name of the accessor method for each field within view structs.
+    accessors: Vec<Ident>,
+    /// Name of the setter method for each field within mutable view structs.
+    setters: Vec<Ident>,
+    /// Name of each field, as it should appear in error messages.
+    names: Vec<String>,
+    /// Type of each field within the base struct.
+    tys: Vec<Type>,
+    /// Layout assertions for each field.
+    assertions: Vec<Option<TokenStream>>,
+    /// Index and member of the field holding the tag, for each union field.
+    union_tags: Vec<Option<(usize, Member)>>,
+    /// Member holding each field within the raw struct, which is shared by grouped bit fields.
+    raw_members: Vec<Member>,
+    /// Location of each bit field within its backing integer.
+    bit_fields: Vec<Option<BitFieldIr>>,
+    /// Byte order of each field, if pinned with `endian`.
+    endians: Vec<Option<Endian>>,
+    /// Padding inserted before each field, and the member of the raw struct holding it.
+    paddings: Vec<Option<(Padding, Member)>>,
+    /// Module providing the raw type and conversions of each field, if provided with `with`.
+    withs: Vec<Option<Path>>,
+    /// Fields omitted from the raw struct, and their value if not [`Default::default`].
+    skipped: Vec<(Member, Option<Expr>)>,
+}
+
+/// Padding inserted before a field within the raw struct.
+enum Padding {
+    /// Pad up to the declared offset of the field.
+    Offset(Expr),
+    /// Pad up to the next multiple of the alignment of the field.
+    Align(Align),
+}
+
+/// Location of a bit field within its backing integer.
+struct BitFieldIr {
+    /// Member of the backing integer within the raw struct.
+    raw_member: Member,
+    /// Type of the backing integer.
+    backing: Ident,
+    /// Width of the field.
+    bits: u32,
+    /// Offset of the field from the least significant bit of the backing integer.
+    shift: u32,
+    /// Whether this is the first field in the group, which declares the backing integer.
+    first: bool,
+}
+
+impl From<Fields> for StructFields {
+    fn from(fields: Fields) -> Self {
+        let (kind, fields) = match fields {
+            Fields::Named(fields) => (
+                FieldsKind::Named,
+                fields
+                    .into_iter()
+                    .map(|(name, ty, config)| {
+                        let setter = format_ident!("set_{name}");
+                        (Member::Named(name.clone()), name, setter, ty, config)
+                    })
+                    .collect(),
+            ),
+            Fields::Unnamed(fields) => (
+                FieldsKind::Unnamed,
+                fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (ty, config))| {
+                        (
+                            Member::Unnamed(Index::from(i)),
+                            format_ident!("_{i}"),
+                            format_ident!("set_{i}"),
+                            ty,
+                            config,
+                        )
+                    })
+                    .collect(),
+            ),
+            Fields::Unit => (FieldsKind::Unit, Vec::new()),
+        };
+
+        let mut out = Self {
+            kind,
+            members: Vec::with_capacity(fields.len()),
+            accessors: Vec::with_capacity(fields.len()),
+            setters: Vec::with_capacity(fields.len()),
+            names: Vec::with_capacity(fields.len()),
+            tys: Vec::with_capacity(fields.len()),
+            assertions: Vec::with_capacity(fields.len()),
+            union_tags: Vec::with_capacity(fields.len()),
+            raw_members: Vec::with_capacity(fields.len()),
+            bit_fields: Vec::with_capacity(fields.len()),
+            endians: Vec::with_capacity(fields.len()),
+            paddings: Vec::with_capacity(fields.len()),
+            withs: Vec::with_capacity(fields.len()),
+            skipped: Vec::new(),
+        };
+
+        // Backing member, remaining bits, and byte order of the current group of bit fields.
+        let mut group = None;
+
+        for (member, accessor, setter, ty, config) in fields {
+            if config.skip {
+                out.skipped.push((member, config.default));
+                continue;
+            }
+
+            let mut endian = config.endian;
+            let bit_field = config.bits.map(|bits| {
+                let first = config.backing.is_some();
+                let (raw_member, backing, remaining, group_endian) = match config.backing {
+                    Some(backing) => {
+                        let width =
+                            backing_width(&backing).expect("backing is an unsigned integer");
+                        (
+                            Member::Named(format_ident!("___cuisiner_bits_{}", member)),
+                            backing,
+                            width,
+                            endian,
+                        )
+                    }
+                    None => group.take().expect("bit field is within a group"),
+                };
+                // Fields are packed from the most significant bit.
+                let shift = remaining - bits;
+                // Every field in the group shares the byte order of the backing integer.
+                endian = group_endian;
+                group = Some((raw_member.clone(), backing.clone(), shift, group_endian));
+
+                BitFieldIr {
+                    raw_member,
+                    backing,
+                    bits,
+                    shift,
+                    first,
+                }
+            });
+            if bit_field.is_none() {
+                group = None;
+            }
+
+            out.raw_members.push(match (&bit_field, &member) {
+                (Some(bit_field), _) => bit_field.raw_member.clone(),
+                // Skipped fields are omitted from the raw struct, so the index may differ.
+                (None, Member::Unnamed(_)) => Member::Unnamed(Index::from(out.members.len())),
+                (None, Member::Named(_)) => member.clone(),
+            });
+            out.bit_fields.push(bit_field);
+            out.endians.push(endian);
+            out.withs.push(config.with);
+            let padding = match (config.offset, config.align) {
+                (Some(offset), _) => Some(Padding::Offset(offset)),
+                (None, Some(align)) => Some(Padding::Align(align)),
+                (None, None) => None,
+            };
+            out.paddings.push(padding.map(|padding| {
+                (
+                    padding,
+                    Member::Named(format_ident!("___cuisiner_padding_{}", member)),
+                )
+            }));
+            out.names.push(match &member {
+                Member::Named(name) => name.unraw().to_string(),
+                Member::Unnamed(index) => index.index.to_string(),
+            });
+            out.members.push(member);
+            out.accessors.push(accessor);
+            out.setters.push(setter);
+            out.tys.push(ty);
+            out.assertions.push(config.assert_layout.map(|metas| {
+                quote! { #[assert_layout(#(#metas,)*)] }
+            }));
+            out.union_tags.push(config.union_tag.map(|tag| {
+                let index = out
+                    .members
+                    .iter()
+                    .position(|member| matches!(member, Member::Named(name) if *name == tag))
+                    .expect("union tag is an earlier field");
+                (index, Member::Named(tag))
+            }));
+        }
+
+        out
+    }
+}
+
+impl StructFields {
+    /// Definitions of each field within the raw struct, including the delimiters. Padding is added
+    /// after the fields to reach `size` if provided, or otherwise to align the struct if
+    /// `c_alignment` is set.
+    fn raw_definitions(
+        &self,
+        crate_name: &Path,
+        b_ident: &Ident,
+        size: Option<&Expr>,
+        c_alignment: bool,
+    ) -> TokenStream {
+        let marker = self.marker_member();
+        let alignments = self.alignments(crate_name);
+
+        // End of the previous field, used to pad up to the offset or alignment of the next field.
+        // The size of the raw types doesn't depend on the byte order, so any can be used to
+        // calculate it.
+        let mut end = quote!(0usize);
+        let definitions = self
+            .raw_tys(crate_name, b_ident)
+            .into_iter()
+            .zip(self.raw_tys(crate_name, &quote!(#crate_name::BigEndian)))
+            .zip(&self.raw_members)
+            .zip(&self.assertions)
+            .zip(&self.paddings)
+            .zip(&alignments)
+            .filter_map(
+                |(((((raw_ty, sized_ty), member), assertion), padding), align)| {
+                    let (raw_ty, sized_ty) = (raw_ty?, sized_ty?);
+                    let padding = padding.as_ref().map(|(padding, padding_member)| {
+                        let len = match padding {
+                            Padding::Offset(offset) => {
+                                let len = quote!(#crate_name::__private::padding(#offset, #end));
+                                end = quote!((#offset));
+                                len
+                            }
+                            Padding::Align(_) => {
+                                let len = quote!(#crate_name::__private::align_padding(#end, #align));
+                                end = quote!(::core::primitive::usize::next_multiple_of(#end, #align));
+                                len
+                            }
+                        };
+
+                        quote!(#padding_member: [u8; #len],)
+                    });
+                    end = quote!(#end + ::core::mem::size_of::<#sized_ty>());
+
+                    Some(match self.kind {
+                        FieldsKind::Named => quote!(#padding #assertion #member: #raw_ty),
+                        _ => quote!(#assertion #raw_ty),
+                    })
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let tail_padding = match (size, c_alignment) {
+            (Some(size), _) => Some(quote!(#crate_name::__private::tail_padding(#size, #end))),
+            (None, true) => {
+                let align = self.container_alignment(crate_name);
+                Some(quote!(#crate_name::__private::align_padding(#end, #align)))
+            }
+            (None, false) => None,
+        }
+        .map(|len| match self.kind {
+            FieldsKind::Named => {
+                let member = self.tail_padding_member();
+                quote!(, #member: [u8; #len])
+            }
+            FieldsKind::Unnamed | FieldsKind::Unit => quote!(, [u8; #len]),
+        });
+
+        // The byte order must be used, even if every field has its byte order pinned.
+        match self.kind {
+            FieldsKind::Named => quote! {
+                { #(#definitions,)* #marker: ::core::marker::PhantomData<#b_ident> #tail_padding }
+            },
+            FieldsKind::Unnamed | FieldsKind::Unit => quote! {
+                (#(#definitions,)* ::core::marker::PhantomData<#b_ident> #tail_padding);
+            },
+        }
+    }
+
+    /// Alignment of each field within the raw struct, which is the alignment a C compiler would
+    /// give its type unless provided with `align`. Union fields are naturally unaligned, and bit
+    /// fields after the first in their group have no raw field of their own.
+    fn alignments(&self, crate_name: &Path) -> Vec<Option<TokenStream>> {
+        self.tys
+            .iter()
+            .zip(&self.union_tags)
+            .zip(&self.bit_fields)
+            .zip(&self.paddings)
+            .zip(&self.withs)
+            .map(|((((ty, union_tag), bit_field), padding), with)| {
+                match (union_tag, bit_field, padding, with) {
+                    (_, Some(BitFieldIr { first: false, .. }), _, _) => None,
+                    (_, _, Some((Padding::Align(Align::Bytes(align)), _)), _) => {
+                        Some(quote!((#align)))
+                    }
+                    (_, Some(BitFieldIr { backing, .. }), _, _) => {
+                        Some(quote!(<#backing as #crate_name::Cuisiner>::ALIGN))
+                    }
+                    (Some(_), None, _, _) => {
+                        Some(quote!(<#ty as #crate_name::CuisinerUnion>::ALIGN))
+                    }
+                    (None, None, _, Some(_)) => Some(quote!(1)),
+                    (None, None, _, None) => Some(quote!(<#ty as #crate_name::Cuisiner>::ALIGN)),
+                }
+            })
+            .collect()
+    }
+
+    /// Alignment of the raw struct when laid out as C would, which is the largest alignment of
+    /// its fields.
+    fn container_alignment(&self, crate_name: &Path) -> TokenStream {
+        let alignments = self.alignments(crate_name).into_iter().flatten();
+        quote!(#crate_name::__private::max_size(&[1, #(#alignments),*]))
+    }
+
+    /// Member of the raw struct which holds the padding after every field, following the byte
+    /// order marker.
+    fn tail_padding_member(&self) -> Member {
+        match self.marker_member() {
+            Member::Named(_) => Member::Named(format_ident!("___cuisiner_tail_padding")),
+            Member::Unnamed(marker) => Member::Unnamed(Index::from(marker.index as usize + 1)),
+        }
+    }
+
+    /// Skipped members, along with the expression used to fill each of them.
+    fn skipped_defaults(&self) -> (Vec<&Member>, Vec<TokenStream>) {
+        self.skipped
+            .iter()
+            .map(|(member, default)| {
+                let value = match default {
+                    Some(default) => quote!(#default),
+                    None => quote!(::core::default::Default::default()),
+                };
+                (member, value)
+            })
+            .unzip()
+    }
+
+    /// Member of the raw struct which holds the byte order marker.
+    fn marker_member(&self) -> Member {
+        match self.kind {
+            FieldsKind::Named => Member::Named(format_ident!("___cuisiner_byte_order")),
+            FieldsKind::Unnamed | FieldsKind::Unit => {
+                Member::Unnamed(Index::from(self.members.len()))
+            }
+        }
+    }
+
+    /// Byte order used to convert each field, which is `b_ident` unless pinned with `endian`.
+    fn byte_orders(&self, crate_name: &Path, b_ident: &impl ToTokens) -> Vec<TokenStream> {
+        self.endians
+            .iter()
+            .map(|endian| byte_order(crate_name, *endian, b_ident))
+            .collect()
+    }
+
+    /// Function converting each field from its raw value, which is provided by the `with` module
+    /// if present.
+    fn conversions_from_raw(&self, crate_name: &Path, orders: &[TokenStream]) -> Vec<TokenStream> {
+        self.tys
+            .iter()
+            .zip(&self.withs)
+            .zip(orders)
+            .map(|((ty, with), order)| match with {
+                Some(with) => quote!(#with::try_from_raw::<#order>),
+                None => quote!(<#ty as #crate_name::Cuisiner>::try_from_raw::<#order>),
+            })
+            .collect()
+    }
+
+    /// Function converting a reference to each field into its raw value, which is provided by the
+    /// `with` module if present.
+    fn conversions_to_raw(&self, crate_name: &Path, orders: &[TokenStream]) -> Vec<TokenStream> {
+        self.tys
+            .iter()
+            .zip(&self.withs)
+            .zip(orders)
+            .map(|((ty, with), order)| match with {
+                Some(with) => quote!(#with::try_to_raw::<#order>),
+                None => quote!(<#ty as #crate_name::Cuisiner>::try_to_raw_ref::<#order>),
+            })
+            .collect()
+    }
+
+    /// Type of each field within the raw struct. Bit fields after the first in their group share
+    /// its backing integer, so have no raw field of their own.
+    fn raw_tys(&self, crate_name: &Path, b_ident: &impl ToTokens) -> Vec<Option<TokenStream>> {
+        self.tys
+            .iter()
+            .zip(&self.union_tags)
+            .zip(&self.bit_fields)
+            .zip(&self.withs)
+            .zip(self.byte_orders(crate_name, b_ident))
+            .map(|((((ty, union_tag), bit_field), with), order)| {
+                match (union_tag, bit_field, with) {
+                    (_, Some(BitFieldIr { first: false, .. }), _) => None,
+                    (_, Some(BitFieldIr { backing, .. }), _) => {
+                        Some(quote!(<#backing as #crate_name::Cuisiner>::Raw::<#order>))
+                    }
+                    (Some(_), None, _) => {
+                        Some(quote!(<#ty as #crate_name::CuisinerUnion>::Raw::<#order>))
+                    }
+                    (None, None, Some(with)) => Some(quote!(#with::Raw::<#order>)),
+                    (None, None, None) => {
+                        Some(quote!(<#ty as #crate_name::Cuisiner>::Raw::<#order>))
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Closures for each field which will attach the field's location to a `CuisinerError`. The
+    /// offset of each field within `raw_ty` is added to `base_offset`, if provided.
+    fn error_mappers(
+        &self,
+        crate_name: &Path,
+        container: &str,
+        raw_ty: &TokenStream,
+        base_offset: Option<&TokenStream>,
+    ) -> Vec<TokenStream> {
+        let base_offset = base_offset.map(|base_offset| quote!(#base_offset +));
+
+        self.raw_members
+            .iter()
+            .zip(&self.names)
+            .map(|(member, name)| {
+                quote! {
+                    |e: #crate_name::CuisinerError| e.in_field(#container, #name, #base_offset ::core::mem::offset_of!(#raw_ty, #member))
+                }
+            })
+            .collect()
+    }
+}
+
+/// Generate a zero-copy view struct, which borrows the raw struct and converts each field on
+/// demand. Mutable views additionally generate a setter for each field, which only writes the bytes
+/// of that field.
+#[allow(clippy::too_many_arguments)]
+fn view_codegen(
+    crate_name: &Path,
+    base_ident: &Ident,
+    visibility: &Visibility,
+    raw_ident: &Ident,
+    view_ident: &Ident,
+    mutable: bool,
+    generics: &StructGenerics,
+    fields: &StructFields,
+) -> TokenStream {
+    let StructGenerics {
+        view: view_generics,
+        raw: raw_generics,
+        view_lifetime,
+        b_ident,
+        ..
+    } = generics;
+    let (view_impl_generics, view_ty_generics, view_where_clause) = view_generics.split_for_impl();
+    let (_, raw_ty_generics, _) = raw_generics.split_for_impl();
+    let (_, base_ty_generics, _) = generics.base.split_for_impl();
+
+    let StructFields {
+        raw_members,
+        accessors,
+        setters,
+        tys,
+        ..
+    } = fields;
+
+    let raw_ty = quote!(#raw_ident #raw_ty_generics);
+
+    // Borrowing the raw value requires its layout to be known, which isn't a requirement of
+    // `Cuisiner::Raw`.
+    let mut impl_generics = view_generics.clone();
+    impl_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#raw_ty: #crate_name::zerocopy::KnownLayout));
+    let impl_where_clause = &impl_generics.where_clause;
+
+    let orders = fields.byte_orders(crate_name, b_ident);
+    let from_raw_fns = fields.conversions_from_raw(crate_name, &orders);
+    let to_raw_fns = fields.conversions_to_raw(crate_name, &orders);
+    let field_errors =
+        fields.error_mappers(crate_name, &base_ident.unraw().to_string(), &raw_ty, None);
+    let accessor_docs = accessors
+        .iter()
+        .map(|accessor| format!("Convert the `{accessor}` field from the underlying bytes."));
+
+    let (view_doc, reference, from_bytes, from_prefix, raw_accessors, setter_fns) = if mutable {
+        let setter_docs = accessors.iter().map(|accessor| {
+            format!("Convert and write the `{accessor}` field, without modifying any other bytes.")
+        });
+
+        (
+            format!("Mutable zero-copy view over the raw representation of [`{base_ident}`]."),
+            quote!(&#view_lifetime mut),
+            quote!(mut_from_bytes),
+            quote!(mut_from_prefix),
+            quote! {
+                /// Raw representation underlying this view.
+                pub fn as_raw(&self) -> &#raw_ty {
+                    self.raw
+                }
+
+                /// Mutable raw representation underlying this view.
+                pub fn as_raw_mut(&mut self) -> &mut #raw_ty {
+                    self.raw
+                }
+            },
+            Some(quote! {
+                #(
+                    #[doc = #setter_docs]
+                    pub fn #setters(&mut self, value: #tys) -> ::core::result::Result<(), #crate_name::CuisinerError> {
+                        self.raw.#raw_members = #to_raw_fns(&value)
+                            .map_err(#field_errors)?;
+                        ::core::result::Result::Ok(())
+                    }
+                )*
+            }),
+        )
+    } else {
+        (
+            format!("Zero-copy view over the raw representation of [`{base_ident}`]."),
+            quote!(&#view_lifetime),
+            quote!(ref_from_bytes),
+            quote!(ref_from_prefix),
+            quote! {
+                /// Raw representation underlying this view.
+                pub fn as_raw(&self) -> &#view_lifetime #raw_ty {
+                    self.raw
+                }
+            },
+            None,
+        )
+    };
+
+    quote! {
+        #[doc = #view_doc]
+        #[automatically_derived]
+        #visibility struct #view_ident #view_generics #view_where_clause {
+            raw: #reference #raw_ty,
+        }
+
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #view_impl_generics #view_ident #view_ty_generics #impl_where_clause {
+            /// Create a view over exactly the provided bytes.
+            pub fn from_bytes(bytes: #reference [u8]) -> ::core::result::Result<Self, #crate_name::CuisinerError> {
+                let found = bytes.len();
+                <#raw_ty as #crate_name::zerocopy::FromBytes>::#from_bytes(bytes)
+                    .map(|raw| Self { raw })
+                    .map_err(|_| #crate_name::CuisinerError::SizeError {
+                        required: ::core::mem::size_of::<#raw_ty>(),
+                        found,
+                    })
+            }
+
+            /// Create a view over the start of the provided bytes, returning the remaining bytes.
+            pub fn from_prefix(bytes: #reference [u8]) -> ::core::result::Result<(Self, #reference [u8]), #crate_name::CuisinerError> {
+                let found = bytes.len();
+                <#raw_ty as #crate_name::zerocopy::FromBytes>::#from_prefix(bytes)
+                    .map(|(raw, rest)| (Self { raw }, rest))
+                    .map_err(|_| #crate_name::CuisinerError::SizeError {
+                        required: ::core::mem::size_of::<#raw_ty>(),
+                        found,
+                    })
+            }
+
+            #raw_accessors
+
+            /// Convert every field, producing the complete value.
+            pub fn to_value(&self) -> ::core::result::Result<#base_ident #base_ty_generics, #crate_name::CuisinerError> {
+                <#base_ident #base_ty_generics as #crate_name::Cuisiner>::try_from_raw::<#b_ident>(
+                    #crate_name::__private::copy_raw(&*self.raw),
+                )
+            }
+
+            #(
+                #[doc = #accessor_docs]
+                pub fn #accessors(&self) -> ::core::result::Result<#tys, #crate_name::CuisinerError> {
+                    #from_raw_fns(#crate_name::__private::copy_raw(&self.raw.#raw_members))
+                    .map_err(#field_errors)
+                }
+            )*
+
+            #setter_fns
+        }
     }
 }
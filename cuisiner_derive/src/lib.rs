@@ -4,7 +4,11 @@ mod lower;
 mod parse;
 
 use proc_macro2::TokenStream;
-use syn::{DeriveInput, Error, Ident, Meta, Token, Type, parenthesized, punctuated::Punctuated};
+use quote::quote;
+use syn::{
+    Attribute, DeriveInput, Error, Expr, Ident, LitInt, LitStr, Meta, Path, Token, Type,
+    meta::ParseNestedMeta, parenthesized, punctuated::Punctuated,
+};
 
 use self::{analyse::*, codegen::*, lower::*, parse::*};
 
@@ -34,13 +38,58 @@ fn derive_cuisiner_inner(derive_input: DeriveInput) -> Result<TokenStream, Error
 #[derive(Clone)]
 enum Fields {
     /// Named fields ([`syn::FieldsNamed`]).
-    Named(Vec<(Ident, Type, Option<Vec<Meta>>)>),
+    Named(Vec<(Ident, Type, FieldConfig)>),
     /// Unnamed fields ([`syn::FieldsUnnamed`]).
-    Unnamed(Vec<(Type, Option<Vec<Meta>>)>),
+    Unnamed(Vec<(Type, FieldConfig)>),
     /// No fields ([`syn::Fields::Unit`]).
     Unit,
 }
 
+impl Fields {
+    /// Configuration of each field, in declaration order.
+    fn configs(&self) -> Vec<&FieldConfig> {
+        match self {
+            Fields::Named(fields) => fields.iter().map(|(_, _, config)| config).collect(),
+            Fields::Unnamed(fields) => fields.iter().map(|(_, config)| config).collect(),
+            Fields::Unit => Vec::new(),
+        }
+    }
+
+    /// Mutable configuration of each field, in declaration order.
+    fn configs_mut(&mut self) -> Vec<&mut FieldConfig> {
+        match self {
+            Fields::Named(fields) => fields.iter_mut().map(|(_, _, config)| config).collect(),
+            Fields::Unnamed(fields) => fields.iter_mut().map(|(_, config)| config).collect(),
+            Fields::Unit => Vec::new(),
+        }
+    }
+
+    /// Pin the byte order of every field without its own `endian` attribute.
+    fn default_endian(&mut self, endian: Option<Endian>) {
+        for config in self.configs_mut() {
+            config.endian = config.endian.or(endian);
+        }
+    }
+
+    /// Align every field without its own `align` or `offset` attribute to its natural alignment.
+    /// Bit fields after the first in their group share its backing integer, so aren't aligned.
+    fn natural_alignment(&mut self) {
+        for config in self.configs_mut() {
+            let grouped_bits = config.bits.is_some() && config.backing.is_none();
+            if config.align.is_none() && config.offset.is_none() && !grouped_bits && !config.skip {
+                config.align = Some(Align::Natural);
+            }
+        }
+    }
+
+    /// Whether any field has layout assertions.
+    fn has_assertions(&self) -> bool {
+        self.configs()
+            .iter()
+            .any(|config| config.assert_layout.is_some())
+    }
+}
+
 impl TryFrom<&syn::Fields> for Fields {
     type Error = Error;
 
@@ -55,35 +104,12 @@ impl TryFrom<&syn::Fields> for Fields {
                             .ident
                             .clone()
                             .expect("named struct field must have ident");
-                        let ty = field.ty.clone();
-
-                        let mut assert_layout = None;
-                        for attr in &field.attrs {
-                            if !attr.path().is_ident("cuisiner") {
-                                continue;
-                            }
-
-                            attr.parse_nested_meta(|meta| {
-                                if meta.path.is_ident("assert") {
-                                    // Remove the parenthesis.
-                                    let args;
-                                    parenthesized!(args in meta.input);
-
-                                    // Fetch the meta items from the attributes.
-                                    assert_layout = Some(
-                                        Punctuated::<Meta, Token![,]>::parse_terminated(&args)?
-                                            .into_iter()
-                                            .collect(),
-                                    );
-
-                                    return Ok(());
-                                }
-
-                                Err(Error::new_spanned(&meta.path, "unknown attribute"))
-                            })?;
-                        }
-
-                        Ok((ident, ty, assert_layout))
+
+                        Ok((
+                            ident,
+                            field.ty.clone(),
+                            FieldConfig::try_from(&*field.attrs)?,
+                        ))
                     })
                     .collect::<Result<_, Error>>()?,
             ),
@@ -91,39 +117,203 @@ impl TryFrom<&syn::Fields> for Fields {
                 fields_unnamed
                     .unnamed
                     .iter()
-                    .map(|field| {
-                        Ok((field.ty.clone(), {
-                            let mut assert_layout = None;
-                            for attr in &field.attrs {
-                                if !attr.path().is_ident("cuisiner") {
-                                    continue;
-                                }
-
-                                attr.parse_nested_meta(|meta| {
-                                    if meta.path.is_ident("assert") {
-                                        // Remove the parenthesis.
-                                        let args;
-                                        parenthesized!(args in meta.input);
-
-                                        // Fetch the meta items from the attributes.
-                                        assert_layout = Some(
-                                            Punctuated::<Meta, Token![,]>::parse_terminated(&args)?
-                                                .into_iter()
-                                                .collect(),
-                                        );
-
-                                        return Ok(());
-                                    }
-
-                                    Err(Error::new_spanned(&meta.path, "unknown attribute"))
-                                })?;
-                            }
-                            assert_layout
-                        }))
-                    })
+                    .map(|field| Ok((field.ty.clone(), FieldConfig::try_from(&*field.attrs)?)))
                     .collect::<Result<_, Error>>()?,
             ),
             syn::Fields::Unit => Fields::Unit,
         })
     }
 }
+
+/// Configuration for a single field, provided via attributes.
+#[derive(Clone, Default)]
+struct FieldConfig {
+    /// Layout assertions for the field within the raw struct.
+    assert_layout: Option<Vec<Meta>>,
+    /// Sibling field holding the discriminator for this `CuisinerUnion` field.
+    union_tag: Option<Ident>,
+    /// Width of the field in bits, when packed into a shared backing integer.
+    bits: Option<u32>,
+    /// Backing integer for a group of bit fields, starting at this field.
+    backing: Option<Ident>,
+    /// Bit of the flags word which this field represents.
+    bit: Option<u32>,
+    /// Whether this field holds any undefined bits of the flags word.
+    unknown_bits: bool,
+    /// Byte order of the field, regardless of the byte order of the container.
+    endian: Option<Endian>,
+    /// Offset of the field within the raw struct, with padding inserted before it to reach it.
+    offset: Option<Expr>,
+    /// Alignment of the field within the raw struct, with padding inserted before it to reach it.
+    align: Option<Align>,
+    /// Module providing the raw type and conversions for the field, instead of `Cuisiner`.
+    with: Option<Path>,
+    /// Whether the field is omitted from the raw struct.
+    skip: bool,
+    /// Value of a skipped field, instead of [`Default::default`].
+    default: Option<Expr>,
+}
+
+/// Alignment of a field within the raw struct.
+#[derive(Clone)]
+enum Align {
+    /// Alignment provided with an `align` attribute.
+    Bytes(Expr),
+    /// Alignment that a C compiler would give the type of the field.
+    Natural,
+}
+
+/// Byte order pinned with an `endian` attribute.
+#[derive(Clone, Copy)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+enum Endian {
+    Big,
+    Little,
+    Native,
+}
+
+impl Endian {
+    /// Parse the value of an `endian` attribute, which may be an identifier or a string.
+    fn parse(meta: &ParseNestedMeta) -> Result<Self, Error> {
+        let value = meta.value()?;
+        let (endian, span) = if value.peek(LitStr) {
+            let endian = value.parse::<LitStr>()?;
+            (endian.value(), endian.span())
+        } else {
+            let endian = value.parse::<Ident>()?;
+            (endian.to_string(), endian.span())
+        };
+
+        match endian.as_str() {
+            "big" => Ok(Self::Big),
+            "little" => Ok(Self::Little),
+            "native" => Ok(Self::Native),
+            endian => Err(Error::new(
+                span,
+                format!("unknown endian: {endian} (expected big, little, or native)"),
+            )),
+        }
+    }
+
+    /// Path of the corresponding `ByteOrder` type.
+    fn byte_order(self, crate_name: &Path) -> TokenStream {
+        match self {
+            Self::Big => quote!(#crate_name::BigEndian),
+            Self::Little => quote!(#crate_name::LittleEndian),
+            Self::Native => quote!(#crate_name::NativeEndian),
+        }
+    }
+}
+
+/// Width in bits of an unsigned integer which can back a group of bit fields.
+fn backing_width(backing: &Ident) -> Option<u32> {
+    Some(match backing.to_string().as_str() {
+        "u8" => 8,
+        "u16" => 16,
+        "u32" => 32,
+        "u64" => 64,
+        "u128" => 128,
+        _ => return None,
+    })
+}
+
+impl TryFrom<&[Attribute]> for FieldConfig {
+    type Error = Error;
+
+    fn try_from(attrs: &[Attribute]) -> Result<Self, Self::Error> {
+        let mut config = Self::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("cuisiner") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("assert") {
+                    // Remove the parenthesis.
+                    let args;
+                    parenthesized!(args in meta.input);
+
+                    // Fetch the meta items from the attributes.
+                    config.assert_layout = Some(
+                        Punctuated::<Meta, Token![,]>::parse_terminated(&args)?
+                            .into_iter()
+                            .collect(),
+                    );
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("bits") {
+                    config.bits = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("endian") {
+                    config.endian = Some(Endian::parse(&meta)?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("bit") {
+                    config.bit = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("unknown_bits") {
+                    config.unknown_bits = true;
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("backing") {
+                    config.backing = Some(meta.value()?.parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("with") {
+                    config.with = Some(meta.value()?.parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("skip") {
+                    config.skip = true;
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("default") {
+                    config.default = Some(meta.value()?.parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("align") {
+                    config.align = Some(Align::Bytes(meta.value()?.parse()?));
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("offset") {
+                    config.offset = Some(meta.value()?.parse()?);
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("union_tag") {
+                    config.union_tag = Some(meta.value()?.parse()?);
+
+                    return Ok(());
+                }
+
+                Err(Error::new_spanned(&meta.path, "unknown attribute"))
+            })?;
+        }
+
+        Ok(config)
+    }
+}
@@ -1,11 +1,12 @@
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{ToTokens, format_ident};
 use syn::{
-    Error, Expr, ExprLit, GenericArgument, GenericParam, Generics, Ident, Lit, Meta, Path, Token,
-    Visibility, parse_quote, parse_quote_spanned, parse2, punctuated::Punctuated, spanned::Spanned,
+    Error, Expr, ExprLit, GenericArgument, GenericParam, Generics, Ident, Lifetime, Lit, Meta,
+    Path, Token, Type, Visibility, parse_quote, parse_quote_spanned, parse2,
+    punctuated::Punctuated, spanned::Spanned,
 };
 
-use crate::{DeriveModel, DeriveModelItem, Fields, Repr};
+use crate::{DeriveModel, DeriveModelItem, Endian, Fields, Repr, UnknownBits, Variant};
 
 /// From the provided [`DeriveModel`], generate an [`Ir`] representing it.
 pub fn lower(model: DeriveModel) -> Result<Ir, Error> {
@@ -19,6 +20,12 @@ pub fn lower(model: DeriveModel) -> Result<Ir, Error> {
                 fields,
                 generics,
                 container_assert_layout,
+                view,
+                view_mut,
+                endian,
+                size,
+                c_alignment,
+                transparent,
             } => {
                 let raw_ident = format!("___Cuisiner{}Raw", model.name);
                 let raw_ident_tokens: TokenStream = raw_ident.parse()?;
@@ -32,6 +39,7 @@ pub fn lower(model: DeriveModel) -> Result<Ir, Error> {
                         parse_quote!(#crate_name::zerocopy::FromBytes),
                         parse_quote!(#crate_name::zerocopy::IntoBytes),
                         parse_quote!(#crate_name::zerocopy::Immutable),
+                        parse_quote!(#crate_name::zerocopy::KnownLayout),
                         parse_quote!(#crate_name::zerocopy::Unaligned),
                     ],
                     container_assert_layout: container_assert_layout.map(
@@ -43,10 +51,66 @@ pub fn lower(model: DeriveModel) -> Result<Ir, Error> {
                             )
                         },
                     ),
-                    generics: StructGenerics::new(generics, &crate_name),
+                    generics: Box::new(StructGenerics::new(generics, &crate_name)),
+                    view_ident: view.then(|| format_ident!("{}Ref", model.name)),
+                    view_mut_ident: view_mut.then(|| format_ident!("{}Mut", model.name)),
+                    endian,
+                    size,
+                    c_alignment,
+                    transparent,
                 }
             }
-            DeriveModelItem::Enum { variants, repr } => ItemIr::Enum { repr, variants },
+            DeriveModelItem::Enum {
+                variants,
+                repr,
+                other,
+                union,
+                endian,
+            } => ItemIr::Enum {
+                repr,
+                union,
+                endian,
+                // Only enums with data-carrying variants require a raw struct, otherwise the tag is
+                // used directly.
+                raw_ident: variants
+                    .iter()
+                    .any(Variant::has_fields)
+                    .then(|| format_ident!("___Cuisiner{}Raw", model.name)),
+                variants: variants
+                    .into_iter()
+                    .map(|variant| VariantIr {
+                        raw_ident: variant.has_fields().then(|| {
+                            format_ident!("___Cuisiner{}{}Raw", model.name, variant.ident)
+                        }),
+                        ident: variant.ident,
+                        discriminant: variant.discriminant,
+                        fields: variant.fields,
+                    })
+                    .collect(),
+                other,
+            },
+            DeriveModelItem::Conversion {
+                try_from,
+                into,
+                endian,
+            } => ItemIr::Conversion {
+                try_from,
+                into,
+                endian,
+            },
+            DeriveModelItem::Flags {
+                flags,
+                repr,
+                unknown_bits,
+                unknown_field,
+                endian,
+            } => ItemIr::Flags {
+                flags,
+                repr,
+                unknown_bits,
+                unknown_field,
+                endian,
+            },
         },
         crate_name,
     })
@@ -74,21 +138,73 @@ pub enum ItemIr {
         raw_ident: Ident,
         /// Derives to be added to the raw struct.
         raw_derives: Vec<Path>,
-        /// Required generics.
-        generics: StructGenerics,
+        /// Required generics. Boxed as it is much larger than every other variant (clippy's
+        /// `large_enum_variant`).
+        generics: Box<StructGenerics>,
         container_assert_layout: Option<Vec<Meta>>,
+        /// Identifier of the zero-copy view struct, if one should be generated.
+        view_ident: Option<Ident>,
+        /// Identifier of the mutable zero-copy view struct, if one should be generated.
+        view_mut_ident: Option<Ident>,
+        /// Byte order of the whole struct, regardless of the caller's byte order.
+        endian: Option<Endian>,
+        /// Total size of the raw struct, with padding inserted after the last field to reach it.
+        size: Option<Box<Expr>>,
+        /// Whether fields are laid out with the alignment a C compiler would give them.
+        c_alignment: bool,
+        /// Whether the raw representation of the only field is used directly, without a raw struct.
+        transparent: bool,
     },
     /// Enum IR.
     Enum {
         repr: Repr,
-        variants: Vec<(Ident, usize)>,
+        variants: Vec<VariantIr>,
+        other: Option<Ident>,
+        /// Whether the enum is a union, with the tag stored outside of the raw representation.
+        union: bool,
+        /// Byte order of the tag, regardless of the caller's byte order.
+        endian: Option<Endian>,
+        /// Identifier of the raw struct holding the tag and body, if any variant carries data.
+        raw_ident: Option<Ident>,
     },
+    /// Flags IR.
+    Flags {
+        /// Field and bit for each flag.
+        flags: Vec<(Ident, u32)>,
+        repr: Repr,
+        unknown_bits: UnknownBits,
+        /// Field holding undefined bits, when they are preserved.
+        unknown_field: Option<Ident>,
+        /// Byte order of the flags word, regardless of the caller's byte order.
+        endian: Option<Endian>,
+    },
+    /// IR of a container converted through another type.
+    Conversion {
+        /// Type which the raw value is converted into, before converting into the container.
+        try_from: Box<Type>,
+        /// Type which the container is converted into, before converting into the raw value.
+        into: Box<Type>,
+        /// Byte order of the whole container, regardless of the caller's byte order.
+        endian: Option<Endian>,
+    },
+}
+
+/// IR of an enum variant.
+pub struct VariantIr {
+    pub ident: Ident,
+    pub discriminant: usize,
+    pub fields: Fields,
+    /// Identifier of the raw struct for the variant's fields, if it carries data.
+    pub raw_ident: Option<Ident>,
 }
 
 /// Generics required for lowering a struct.
 pub struct StructGenerics {
     pub base: Generics,
     pub raw: Generics,
+    /// Generics for view structs, which borrow the raw struct.
+    pub view: Generics,
+    pub view_lifetime: Lifetime,
     pub b_ident: Ident,
     pub b_generic: GenericParam,
 }
@@ -102,9 +218,16 @@ impl StructGenerics {
         let mut raw = base.clone();
         raw.params.push(b_generic.clone());
 
+        // Lifetime of the borrow within view structs.
+        let view_lifetime: Lifetime = parse_quote!('___cuisiner_view);
+        let mut view = raw.clone();
+        view.params.insert(0, parse_quote!(#view_lifetime));
+
         Self {
             base,
             raw,
+            view,
+            view_lifetime,
             b_ident,
             b_generic,
         }
@@ -186,6 +309,8 @@ mod test {
     use proc_macro2::Span;
     use syn::Visibility;
 
+    use crate::FieldConfig;
+
     fn test_struct_ir(model: DeriveModel, expected_raw_ident: impl AsRef<str>) {
         let ir = lower(model).unwrap();
         let ItemIr::Struct { raw_ident, .. } = ir.item else {
@@ -202,9 +327,19 @@ mod test {
                 name: Ident::new("MyStruct", Span::call_site()),
                 visibility: Visibility::Inherited,
                 item: DeriveModelItem::Struct {
-                    fields: Fields::Named(vec![(parse_quote!(a), parse_quote!(u64), None)]),
+                    fields: Fields::Named(vec![(
+                        parse_quote!(a),
+                        parse_quote!(u64),
+                        FieldConfig::default(),
+                    )]),
                     generics: Default::default(),
                     container_assert_layout: None,
+                    view: false,
+                    view_mut: false,
+                    endian: None,
+                    size: None,
+                    c_alignment: false,
+                    transparent: false,
                 },
             },
             "___CuisinerMyStructRaw",
@@ -218,21 +353,86 @@ mod test {
             visibility: Visibility::Inherited,
             item: DeriveModelItem::Enum {
                 variants: vec![
-                    (parse_quote!(First), 1),
-                    (parse_quote!(Second), 2),
-                    (parse_quote!(Third), 3),
+                    Variant {
+                        ident: parse_quote!(First),
+                        discriminant: 1,
+                        fields: Fields::Unit,
+                    },
+                    Variant {
+                        ident: parse_quote!(Second),
+                        discriminant: 2,
+                        fields: Fields::Unit,
+                    },
+                    Variant {
+                        ident: parse_quote!(Third),
+                        discriminant: 3,
+                        fields: Fields::Unit,
+                    },
                 ],
                 repr: Repr::U32,
+                other: None,
+                union: false,
+                endian: None,
             },
         })
         .unwrap();
 
-        let ItemIr::Enum { repr, variants } = ir.item else {
+        let ItemIr::Enum {
+            repr,
+            variants,
+            raw_ident,
+            ..
+        } = ir.item
+        else {
             panic!("expected enum item");
         };
 
         assert_eq!(repr, Repr::U32);
         assert_eq!(variants.len(), 3);
+        assert!(raw_ident.is_none());
+    }
+
+    #[test]
+    fn valid_data_enum_model() {
+        let ir = lower(DeriveModel {
+            name: Ident::new("MyEnum", Span::call_site()),
+            visibility: Visibility::Inherited,
+            item: DeriveModelItem::Enum {
+                variants: vec![
+                    Variant {
+                        ident: parse_quote!(First),
+                        discriminant: 1,
+                        fields: Fields::Unnamed(vec![(parse_quote!(u32), FieldConfig::default())]),
+                    },
+                    Variant {
+                        ident: parse_quote!(Second),
+                        discriminant: 2,
+                        fields: Fields::Unit,
+                    },
+                ],
+                repr: Repr::U8,
+                other: None,
+                union: false,
+                endian: None,
+            },
+        })
+        .unwrap();
+
+        let ItemIr::Enum {
+            variants,
+            raw_ident,
+            ..
+        } = ir.item
+        else {
+            panic!("expected enum item");
+        };
+
+        assert_eq!(raw_ident.unwrap(), "___CuisinerMyEnumRaw");
+        assert_eq!(
+            variants[0].raw_ident.as_ref().unwrap(),
+            "___CuisinerMyEnumFirstRaw"
+        );
+        assert!(variants[1].raw_ident.is_none());
     }
     mod extend_assert_generics {
         use syn::parse_quote;
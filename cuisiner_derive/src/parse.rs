@@ -23,7 +23,10 @@ pub fn parse(derive_input: DeriveInput) -> Result<Ast, Error> {
             variants: data_enum.variants,
         }),
         syn::Data::Union(_) => {
-            return Err(Error::new_spanned(derive_input, "`union` is not supported"));
+            return Err(Error::new_spanned(
+                derive_input,
+                "`union` is not supported, use an enum with `#[cuisiner(union, repr = ...)]` instead",
+            ));
         }
     })
 }
@@ -1,5 +1,7 @@
 use cuisiner::{BigEndian, Cuisiner};
 
+// Only used for the compile-time layout assertions.
+#[allow(dead_code)]
 mod primitive {
     use super::*;
 
@@ -31,6 +33,7 @@ mod random {
     use super::*;
 
     #[derive(Cuisiner, Debug, PartialEq, Eq)]
+    #[cuisiner(view)]
     struct MyStruct<T: Cuisiner> {
         value: u32,
         nested: T,
@@ -85,4 +88,18 @@ mod random {
             MyStruct::from_bytes::<BigEndian>(&bytes).unwrap()
         );
     }
+
+    #[test]
+    fn generic_view() {
+        let bytes = MyStruct {
+            value: 1234,
+            nested: InnerU8 { value: 0xff },
+        }
+        .to_bytes::<BigEndian>()
+        .unwrap();
+
+        let view = MyStructRef::<InnerU8, BigEndian>::from_bytes(&bytes).unwrap();
+        assert_eq!(view.value().unwrap(), 1234);
+        assert_eq!(view.nested().unwrap(), InnerU8 { value: 0xff });
+    }
 }
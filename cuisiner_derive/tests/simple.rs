@@ -1,4 +1,5 @@
-use cuisiner::{BigEndian, Cuisiner};
+use cuisiner::{BigEndian, Cuisiner, CuisinerError, CuisinerUnion, LittleEndian};
+use std::any::TypeId;
 
 #[derive(Clone, Cuisiner, Debug, PartialEq, Eq)]
 struct MyStruct {
@@ -28,3 +29,931 @@ fn deserialse() {
 
     assert_eq!(s, s2);
 }
+
+#[test]
+fn read_write() {
+    let s = MyStruct {
+        a: -12,
+        b: 1234,
+        s: S2 { thing: 4321 },
+    };
+
+    let mut buf = Vec::new();
+    s.clone().write_to::<BigEndian, _>(&mut buf).unwrap();
+
+    let s2 = MyStruct::read_from::<BigEndian, _>(buf.as_slice()).unwrap();
+
+    assert_eq!(s, s2);
+}
+
+#[test]
+fn prefix_suffix() {
+    let mut buf = [0u8; 18];
+    S2 { thing: 1 }
+        .write_to_prefix::<BigEndian>(&mut buf)
+        .unwrap();
+    S2 { thing: 2 }
+        .write_to_suffix::<BigEndian>(&mut buf)
+        .unwrap();
+    assert!(
+        S2 { thing: 3 }
+            .write_to_prefix::<BigEndian>(&mut buf[..4])
+            .is_err()
+    );
+
+    let (first, rest) = S2::read_from_prefix::<BigEndian>(&buf).unwrap();
+    assert_eq!(first, S2 { thing: 1 });
+    assert_eq!(rest.len(), 10);
+
+    let (rest, last) = S2::read_from_suffix::<BigEndian>(rest).unwrap();
+    assert_eq!(last, S2 { thing: 2 });
+    assert_eq!(rest, [0, 0]);
+
+    assert!(S2::read_from_bytes::<BigEndian>(&buf).is_err());
+    assert_eq!(
+        S2::read_from_bytes::<BigEndian>(&buf[..8]).unwrap(),
+        S2 { thing: 1 }
+    );
+}
+
+#[test]
+fn write_into_buffer() {
+    let mut page = [0u8; 20];
+    let mut offset = 0;
+    offset += S2 { thing: 1 }
+        .write_into::<BigEndian>(&mut page[offset..])
+        .unwrap();
+    offset += S2 { thing: 2 }
+        .write_into::<BigEndian>(&mut page[offset..])
+        .unwrap();
+    assert_eq!(offset, 16);
+
+    assert!(matches!(
+        S2 { thing: 3 }.write_into::<BigEndian>(&mut page[offset..]),
+        Err(CuisinerError::SizeError {
+            required: 8,
+            found: 4
+        })
+    ));
+
+    let mut vec = page[..offset].to_vec();
+    assert_eq!(
+        S2 { thing: 3 }.extend_vec::<BigEndian>(&mut vec).unwrap(),
+        8
+    );
+    assert_eq!(vec.len(), 24);
+    assert_eq!(
+        S2::read_from_suffix::<BigEndian>(&vec).unwrap().1,
+        S2 { thing: 3 }
+    );
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(view, view_mut)]
+struct Tuple(u16, S2);
+
+#[test]
+fn tuple_struct() {
+    let bytes = Tuple(7, S2 { thing: 42 }).to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [0, 7, 0, 0, 0, 0, 0, 0, 0, 42]);
+    assert_eq!(
+        Tuple::from_bytes::<BigEndian>(&bytes).unwrap(),
+        Tuple(7, S2 { thing: 42 })
+    );
+
+    let view = TupleRef::<BigEndian>::from_bytes(&bytes).unwrap();
+    assert_eq!(view._0().unwrap(), 7);
+    assert_eq!(view._1().unwrap(), S2 { thing: 42 });
+
+    let mut bytes = bytes;
+    let mut view = TupleMut::<BigEndian>::from_bytes(&mut bytes).unwrap();
+    view.set_0(8).unwrap();
+    assert_eq!(view.to_value().unwrap(), Tuple(8, S2 { thing: 42 }));
+}
+
+#[derive(Clone, Copy, Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(repr = u8)]
+enum Colour {
+    Red = 1,
+    Green = 2,
+    Blue = 3,
+}
+
+#[test]
+fn enum_array() {
+    let colours = [Colour::Blue, Colour::Red, Colour::Green];
+    let bytes = colours.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [3, 1, 2]);
+    assert_eq!(
+        <[Colour; 3]>::from_bytes::<BigEndian>(&bytes).unwrap(),
+        colours
+    );
+
+    assert!(<[Colour; 3]>::from_bytes::<BigEndian>(&[1, 0, 2]).is_err());
+}
+
+#[derive(Cuisiner, Debug)]
+struct Outer {
+    header: u32,
+    colours: [Colour; 2],
+}
+
+#[test]
+fn nested_error_location() {
+    let e = Outer::from_bytes::<BigEndian>(&[0, 0, 0, 0, 1, 9]).unwrap_err();
+    assert_eq!(e.path().unwrap().to_string(), "Outer.colours[1]");
+    assert_eq!(e.offset(), Some(5));
+    assert!(matches!(
+        e.root_cause(),
+        CuisinerError::InvalidDiscriminant {
+            type_name: "Colour",
+            value,
+        } if value.to_string() == "9"
+    ));
+
+    let e = Tuple::from_bytes::<BigEndian>(&[0; 2]).unwrap_err();
+    assert!(e.path().is_none());
+}
+
+// Rust requires `repr` for explicit discriminants alongside the non-unit `other` variant.
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(repr = u32)]
+#[repr(u32)]
+enum TextEncoding {
+    Utf8 = 1,
+    Utf16Le = 2,
+    #[cuisiner(other)]
+    Unknown(u32),
+}
+
+#[test]
+fn enum_other() {
+    assert_eq!(
+        TextEncoding::from_bytes::<BigEndian>(&[0, 0, 0, 2]).unwrap(),
+        TextEncoding::Utf16Le
+    );
+
+    let unknown = TextEncoding::from_bytes::<BigEndian>(&[0, 0, 0, 7]).unwrap();
+    assert_eq!(unknown, TextEncoding::Unknown(7));
+    assert_eq!(unknown.to_bytes::<BigEndian>().unwrap(), [0, 0, 0, 7]);
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(repr = u8)]
+#[repr(u8)]
+enum Message {
+    Ping = 1,
+    Move { x: u16, y: u16 } = 2,
+    Paint(Colour) = 3,
+}
+
+#[test]
+fn tagged_enum() {
+    assert_eq!(size_of::<<Message as Cuisiner>::Raw<BigEndian>>(), 5);
+
+    let value = Message::Move { x: 0x0102, y: 3 };
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [2, 1, 2, 0, 3]);
+    assert_eq!(Message::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+
+    // Unused bytes of the body are zeroed.
+    let value = Message::Paint(Colour::Blue);
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [3, 3, 0, 0, 0]);
+    assert_eq!(Message::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+
+    let bytes = Message::Ping.to_bytes::<LittleEndian>().unwrap();
+    assert_eq!(bytes, [1, 0, 0, 0, 0]);
+    assert_eq!(
+        Message::from_bytes::<LittleEndian>(&bytes).unwrap(),
+        Message::Ping
+    );
+}
+
+#[test]
+fn tagged_enum_errors() {
+    let e = Message::from_bytes::<BigEndian>(&[3, 9, 0, 0, 0]).unwrap_err();
+    assert_eq!(e.path().unwrap().to_string(), "Message::Paint.0");
+    assert_eq!(e.offset(), Some(1));
+
+    let e = Message::from_bytes::<BigEndian>(&[4, 0, 0, 0, 0]).unwrap_err();
+    assert!(matches!(
+        e,
+        CuisinerError::InvalidDiscriminant {
+            type_name: "Message",
+            ..
+        }
+    ));
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(union, repr = u8)]
+#[repr(u8)]
+enum Shape {
+    Circle { radius: u16 } = 1,
+    Rect { width: u16, height: u16 } = 2,
+    Empty = 3,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+struct Drawing {
+    kind: u8,
+    #[cuisiner(union_tag = kind)]
+    shape: Shape,
+    colour: Colour,
+}
+
+#[test]
+fn union() {
+    assert_eq!(size_of::<<Drawing as Cuisiner>::Raw<BigEndian>>(), 6);
+
+    let drawing = Drawing {
+        kind: 1,
+        shape: Shape::Circle { radius: 0x0102 },
+        colour: Colour::Green,
+    };
+    let bytes = drawing.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [1, 1, 2, 0, 0, 2]);
+    assert_eq!(Drawing::from_bytes::<BigEndian>(&bytes).unwrap(), drawing);
+
+    let drawing = Drawing::from_bytes::<BigEndian>(&[2, 0, 3, 0, 4, 1]).unwrap();
+    assert_eq!(
+        drawing.shape,
+        Shape::Rect {
+            width: 3,
+            height: 4
+        }
+    );
+
+    let e = Drawing::from_bytes::<BigEndian>(&[9, 0, 0, 0, 0, 1]).unwrap_err();
+    assert_eq!(e.path().unwrap().to_string(), "Drawing.shape");
+    assert_eq!(e.offset(), Some(1));
+    assert!(matches!(
+        e.root_cause(),
+        CuisinerError::InvalidDiscriminant {
+            type_name: "Shape",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn union_tag_mismatch() {
+    let drawing = Drawing {
+        kind: 2,
+        shape: Shape::Empty,
+        colour: Colour::Red,
+    };
+    let e = drawing.to_bytes::<BigEndian>().unwrap_err();
+    assert!(matches!(
+        e.root_cause(),
+        CuisinerError::UnionTagMismatch { tag, found }
+            if tag.to_string() == "2" && found.to_string() == "3"
+    ));
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(repr = u8)]
+enum ShapeKind {
+    Circle = 1,
+    Rect = 2,
+    Empty = 3,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+struct KindDrawing {
+    kind: ShapeKind,
+    #[cuisiner(union_tag = kind)]
+    shape: Shape,
+}
+
+#[test]
+fn union_enum_tag() {
+    let drawing = KindDrawing {
+        kind: ShapeKind::Circle,
+        shape: Shape::Circle { radius: 0x0102 },
+    };
+    let bytes = drawing.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [1, 1, 2, 0, 0]);
+    assert_eq!(
+        KindDrawing::from_bytes::<BigEndian>(&bytes).unwrap(),
+        drawing
+    );
+
+    let drawing = KindDrawing {
+        kind: ShapeKind::Rect,
+        shape: Shape::Empty,
+    };
+    let e = drawing.to_bytes::<BigEndian>().unwrap_err();
+    assert!(matches!(
+        e.root_cause(),
+        CuisinerError::UnionTagMismatch { .. }
+    ));
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(assert(size = 5))]
+struct PacketHeader {
+    #[cuisiner(bits = 4, backing = u8, assert(offset = 0, size = 1))]
+    version: u8,
+    #[cuisiner(bits = 4)]
+    header_length: u8,
+    #[cuisiner(bits = 3, backing = u16, assert(offset = 1, size = 2))]
+    flags: u8,
+    #[cuisiner(bits = 1)]
+    urgent: bool,
+    #[cuisiner(bits = 12)]
+    fragment_offset: u16,
+    length: u16,
+}
+
+#[test]
+fn bit_fields() {
+    assert_eq!(size_of::<<PacketHeader as Cuisiner>::Raw<BigEndian>>(), 5);
+
+    let header = PacketHeader {
+        version: 4,
+        header_length: 5,
+        flags: 0b101,
+        urgent: true,
+        fragment_offset: 0x123,
+        length: 20,
+    };
+    let bytes = header.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [0x45, 0b1011_0001, 0x23, 0, 20]);
+    assert_eq!(
+        PacketHeader::from_bytes::<BigEndian>(&bytes).unwrap(),
+        header
+    );
+
+    // The backing integer follows the byte order.
+    let bytes = header.to_bytes::<LittleEndian>().unwrap();
+    assert_eq!(bytes, [0x45, 0x23, 0b1011_0001, 20, 0]);
+    assert_eq!(
+        PacketHeader::from_bytes::<LittleEndian>(&bytes).unwrap(),
+        header
+    );
+}
+
+#[test]
+fn bit_field_overflow() {
+    let header = PacketHeader {
+        version: 4,
+        header_length: 16,
+        flags: 0,
+        urgent: false,
+        fragment_offset: 0,
+        length: 0,
+    };
+    let e = header.to_bytes::<BigEndian>().unwrap_err();
+    assert_eq!(e.path().unwrap().to_string(), "PacketHeader.header_length");
+    assert_eq!(e.offset(), Some(0));
+    assert!(matches!(
+        e.root_cause(),
+        CuisinerError::BitFieldOverflow { bits: 4, value } if value.to_string() == "16"
+    ));
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(flags, repr = u16)]
+struct Permissions {
+    #[cuisiner(bit = 0)]
+    read: bool,
+    #[cuisiner(bit = 1)]
+    write: bool,
+    #[cuisiner(bit = 9)]
+    execute: bool,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(flags, repr = u16, unknown_bits = drop)]
+struct LenientPermissions {
+    #[cuisiner(bit = 0)]
+    read: bool,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(flags, repr = u16, unknown_bits = preserve)]
+struct PreservedPermissions {
+    #[cuisiner(bit = 0)]
+    read: bool,
+    #[cuisiner(unknown_bits)]
+    other: u16,
+}
+
+#[test]
+fn flags() {
+    assert_eq!(size_of::<<Permissions as Cuisiner>::Raw<BigEndian>>(), 2);
+
+    let permissions = Permissions {
+        read: true,
+        write: false,
+        execute: true,
+    };
+    let bytes = permissions.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [0b10, 0b1]);
+    assert_eq!(
+        Permissions::from_bytes::<BigEndian>(&bytes).unwrap(),
+        permissions
+    );
+}
+
+#[test]
+fn flags_unknown_bits() {
+    let bytes = [0b1000_0000, 0b1];
+
+    let e = Permissions::from_bytes::<BigEndian>(&bytes).unwrap_err();
+    assert!(matches!(
+        e,
+        CuisinerError::UnknownBits {
+            type_name: "Permissions",
+            bits,
+        } if bits.to_string() == "32768"
+    ));
+
+    let lenient = LenientPermissions::from_bytes::<BigEndian>(&bytes).unwrap();
+    assert_eq!(lenient, LenientPermissions { read: true });
+    assert_eq!(lenient.to_bytes::<BigEndian>().unwrap(), [0, 0b1]);
+
+    let preserved = PreservedPermissions::from_bytes::<BigEndian>(&bytes).unwrap();
+    assert_eq!(
+        preserved,
+        PreservedPermissions {
+            read: true,
+            other: 0x8000,
+        }
+    );
+    assert_eq!(preserved.to_bytes::<BigEndian>().unwrap(), bytes);
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+struct MixedEndian {
+    #[cuisiner(endian = "little")]
+    length: u16,
+    #[cuisiner(endian = big)]
+    id: u32,
+    value: u16,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(endian = big)]
+struct BigOnly {
+    a: u16,
+    #[cuisiner(endian = little)]
+    b: u16,
+    colour: Colour,
+}
+
+#[test]
+fn field_endian() {
+    let value = MixedEndian {
+        length: 0x0102,
+        id: 0x03040506,
+        value: 0x0708,
+    };
+
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [2, 1, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(MixedEndian::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+
+    // Only the unpinned field follows the caller's byte order.
+    let bytes = value.to_bytes::<LittleEndian>().unwrap();
+    assert_eq!(bytes, [2, 1, 3, 4, 5, 6, 8, 7]);
+    assert_eq!(
+        MixedEndian::from_bytes::<LittleEndian>(&bytes).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn container_endian() {
+    let value = BigOnly {
+        a: 0x0102,
+        b: 0x0304,
+        colour: Colour::Red,
+    };
+
+    let bytes = value.to_bytes().unwrap();
+    assert_eq!(bytes, [1, 2, 4, 3, 1]);
+    assert_eq!(BigOnly::from_bytes(&bytes).unwrap(), value);
+    assert_eq!(BigOnly::read_from(bytes.as_slice()).unwrap(), value);
+
+    // The byte order provided through the trait is ignored.
+    assert_eq!(Cuisiner::to_bytes::<LittleEndian>(&value).unwrap(), bytes);
+    assert_eq!(
+        <BigOnly as Cuisiner>::from_bytes::<LittleEndian>(&bytes).unwrap(),
+        value
+    );
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(repr = u16, endian = little)]
+enum LittleTag {
+    First = 1,
+    Second = 0x0200,
+}
+
+#[test]
+fn enum_endian() {
+    assert_eq!(LittleTag::Second.to_bytes().unwrap(), [0x00, 0x02]);
+    assert_eq!(LittleTag::from_bytes(&[1, 0]).unwrap(), LittleTag::First);
+    assert_eq!(
+        Cuisiner::to_bytes::<BigEndian>(&LittleTag::Second).unwrap(),
+        [0x00, 0x02]
+    );
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+struct Sparse {
+    magic: u16,
+    #[cuisiner(offset = 4)]
+    length: u32,
+    colour: Colour,
+    #[cuisiner(offset = 0x10)]
+    checksum: u16,
+}
+
+#[test]
+fn field_offsets() {
+    assert_eq!(size_of::<<Sparse as Cuisiner>::Raw<BigEndian>>(), 18);
+
+    let value = Sparse {
+        magic: 0x0102,
+        length: 5,
+        colour: Colour::Blue,
+        checksum: 9,
+    };
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(
+        bytes,
+        [1, 2, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 0, 0, 9]
+    );
+    assert_eq!(Sparse::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+
+    let mut bytes = bytes;
+    bytes[8] = 7;
+    let e = Sparse::from_bytes::<BigEndian>(&bytes).unwrap_err();
+    assert_eq!(e.path().unwrap().to_string(), "Sparse.colour");
+    assert_eq!(e.offset(), Some(8));
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(size = 16, view)]
+struct BootRecord {
+    signature: u16,
+    sectors: u32,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(size = 4)]
+struct Padded(u8);
+
+#[test]
+fn container_size() {
+    assert_eq!(size_of::<<BootRecord as Cuisiner>::Raw<BigEndian>>(), 16);
+
+    let value = BootRecord {
+        signature: 0x55aa,
+        sectors: 0x0102,
+    };
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(
+        bytes,
+        [0x55, 0xaa, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(BootRecord::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+
+    let view = BootRecordRef::<BigEndian>::from_bytes(&bytes).unwrap();
+    assert_eq!(view.sectors().unwrap(), 0x0102);
+    assert!(BootRecordRef::<BigEndian>::from_bytes(&bytes[..6]).is_err());
+
+    let bytes = Padded(7).to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [7, 0, 0, 0]);
+    assert_eq!(Padded::from_bytes::<BigEndian>(&bytes).unwrap(), Padded(7));
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(c_alignment)]
+struct CLayout {
+    a: u8,
+    b: u32,
+    c: u16,
+    d: u64,
+    e: u8,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(c_alignment)]
+struct CNested {
+    a: u8,
+    inner: CLayout,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(repr = u32)]
+#[repr(u32)]
+enum Command {
+    Stop = 0,
+    Seek(u16) = 1,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(c_alignment)]
+struct CTagged {
+    a: u8,
+    command: Command,
+    kind: u8,
+    #[cuisiner(union_tag = kind)]
+    shape: Shape,
+}
+
+#[test]
+fn c_alignment() {
+    // `a` at 0, `b` at 4, `c` at 8, `d` at 16, and `e` at 24, rounded up to the alignment of `d`.
+    assert_eq!(size_of::<<CLayout as Cuisiner>::Raw<BigEndian>>(), 32);
+    assert_eq!(CLayout::ALIGN, 8);
+    assert_eq!(TextEncoding::ALIGN, 4);
+    assert_eq!(size_of::<<CNested as Cuisiner>::Raw<BigEndian>>(), 40);
+
+    let value = CNested {
+        a: 1,
+        inner: CLayout {
+            a: 2,
+            b: 3,
+            c: 4,
+            d: 5,
+            e: 6,
+        },
+    };
+    let bytes = value.to_bytes::<LittleEndian>().unwrap();
+    assert_eq!(bytes[8], 2);
+    assert_eq!(bytes[8 + 4], 3);
+    assert_eq!(bytes[8 + 16], 5);
+    assert_eq!(bytes[8 + 24], 6);
+    assert_eq!(CNested::from_bytes::<LittleEndian>(&bytes).unwrap(), value);
+
+    // `command` at 4, aligned to its tag, `kind` at 10, and `shape` at 12, aligned to its members.
+    assert_eq!(Command::ALIGN, 4);
+    assert_eq!(Message::ALIGN, 2);
+    assert_eq!(<Shape as CuisinerUnion>::ALIGN, 2);
+    assert_eq!(size_of::<<CTagged as Cuisiner>::Raw<BigEndian>>(), 16);
+
+    let value = CTagged {
+        a: 1,
+        command: Command::Seek(2),
+        kind: 1,
+        shape: Shape::Circle { radius: 3 },
+    };
+    let bytes = value.to_bytes::<LittleEndian>().unwrap();
+    assert_eq!(bytes[4], 1);
+    assert_eq!(bytes[8], 2);
+    assert_eq!(bytes[10], 1);
+    assert_eq!(bytes[12], 3);
+    assert_eq!(CTagged::from_bytes::<LittleEndian>(&bytes).unwrap(), value);
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+struct FieldAlign {
+    a: u8,
+    #[cuisiner(align = 4)]
+    b: u16,
+}
+
+#[test]
+fn field_align() {
+    let value = FieldAlign { a: 1, b: 2 };
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [1, 0, 0, 0, 0, 2]);
+    assert_eq!(FieldAlign::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+struct Page {
+    size: u16,
+    #[cuisiner(skip)]
+    path: std::path::PathBuf,
+    #[cuisiner(skip, default = 4096)]
+    capacity: u32,
+    kind: u8,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(view, view_mut)]
+struct Cached(u16, #[cuisiner(skip)] String, u8);
+
+#[test]
+fn skip() {
+    assert_eq!(size_of::<<Page as Cuisiner>::Raw<BigEndian>>(), 3);
+
+    let value = Page {
+        size: 0x0102,
+        path: "/tmp/page".into(),
+        capacity: 1,
+        kind: 3,
+    };
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [1, 2, 3]);
+    assert_eq!(
+        Page::from_bytes::<BigEndian>(&bytes).unwrap(),
+        Page {
+            size: 0x0102,
+            path: Default::default(),
+            capacity: 4096,
+            kind: 3,
+        }
+    );
+
+    let mut bytes = Cached(7, "cached".to_string(), 8)
+        .to_bytes::<BigEndian>()
+        .unwrap();
+    assert_eq!(bytes, [0, 7, 8]);
+
+    let mut view = CachedMut::<BigEndian>::from_bytes(&mut bytes).unwrap();
+    assert_eq!(view._2().unwrap(), 8);
+    view.set_2(9).unwrap();
+    assert_eq!(view.to_value().unwrap(), Cached(7, String::new(), 9));
+}
+
+/// Durations stored as a whole number of seconds.
+mod duration_secs {
+    use std::time::Duration;
+
+    use cuisiner::{ByteOrder, Cuisiner, CuisinerError, zerocopy::U32};
+
+    pub type Raw<B> = U32<B>;
+
+    pub fn try_from_raw<B: ByteOrder>(raw: Raw<B>) -> Result<Duration, CuisinerError> {
+        Ok(Duration::from_secs(u32::try_from_raw::<B>(raw)?.into()))
+    }
+
+    pub fn try_to_raw<B: ByteOrder>(value: &Duration) -> Result<Raw<B>, CuisinerError> {
+        u32::try_from(value.as_secs())
+            .map_err(CuisinerError::custom)?
+            .try_to_raw::<B>()
+    }
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(view)]
+struct Timeout {
+    id: u8,
+    #[cuisiner(with = duration_secs)]
+    after: std::time::Duration,
+}
+
+#[test]
+fn with_module() {
+    use std::time::Duration;
+
+    let value = Timeout {
+        id: 1,
+        after: Duration::from_secs(0x0203),
+    };
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [1, 0, 0, 2, 3]);
+    assert_eq!(Timeout::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+
+    let view = TimeoutRef::<BigEndian>::from_bytes(&bytes).unwrap();
+    assert_eq!(view.after().unwrap(), Duration::from_secs(0x0203));
+
+    let e = Timeout {
+        id: 1,
+        after: Duration::from_secs(u64::MAX),
+    }
+    .to_bytes::<BigEndian>()
+    .unwrap_err();
+    assert_eq!(e.path().unwrap().to_string(), "Timeout.after");
+    assert_eq!(e.offset(), Some(1));
+}
+
+/// Page size, where `1` encodes 65536.
+#[derive(Clone, Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(try_from = "u16", into = "u16")]
+struct PageSize(u32);
+
+impl TryFrom<u16> for PageSize {
+    type Error = String;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self(65536)),
+            n @ 512.. if n.is_power_of_two() => Ok(Self(n.into())),
+            n => Err(format!("invalid page size: {n}")),
+        }
+    }
+}
+
+impl From<PageSize> for u16 {
+    fn from(value: PageSize) -> Self {
+        match value.0 {
+            65536 => 1,
+            n => n as u16,
+        }
+    }
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+struct Database {
+    page_size: PageSize,
+    pages: u16,
+}
+
+#[test]
+fn try_from_into() {
+    assert_eq!(size_of::<<PageSize as Cuisiner>::Raw<BigEndian>>(), 2);
+
+    let value = Database {
+        page_size: PageSize(65536),
+        pages: 3,
+    };
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [0, 1, 0, 3]);
+    assert_eq!(Database::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+    assert_eq!(
+        PageSize::from_bytes::<BigEndian>(&[0x10, 0]).unwrap(),
+        PageSize(4096)
+    );
+
+    let e = Database::from_bytes::<BigEndian>(&[0, 3, 0, 0]).unwrap_err();
+    assert_eq!(e.path().unwrap().to_string(), "Database.page_size");
+    assert_eq!(e.root_cause().to_string(), "invalid page size: 3");
+}
+
+/// Number of free pages, which is never zero.
+#[derive(Clone, Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(try_from = "u16", into = "std::num::NonZero<u16>")]
+struct FreePages(std::num::NonZero<u16>);
+
+impl TryFrom<u16> for FreePages {
+    type Error = String;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        std::num::NonZero::new(value)
+            .map(Self)
+            .ok_or_else(|| "no free pages".to_string())
+    }
+}
+
+impl From<FreePages> for std::num::NonZero<u16> {
+    fn from(value: FreePages) -> Self {
+        value.0
+    }
+}
+
+#[test]
+fn try_from_into_different_types() {
+    let value = FreePages(std::num::NonZero::new(0x0102).unwrap());
+    let bytes = value.to_bytes::<BigEndian>().unwrap();
+    assert_eq!(bytes, [1, 2]);
+    assert_eq!(FreePages::from_bytes::<BigEndian>(&bytes).unwrap(), value);
+
+    let e = FreePages::from_bytes::<BigEndian>(&[0, 0]).unwrap_err();
+    assert_eq!(e.root_cause().to_string(), "no free pages");
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(transparent)]
+struct Offset(u32);
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(transparent)]
+struct Tagged {
+    #[cuisiner(endian = "big")]
+    value: u16,
+    #[cuisiner(skip, default = 7)]
+    cached: u8,
+}
+
+#[derive(Cuisiner, Debug, PartialEq, Eq)]
+#[cuisiner(view)]
+struct Extent {
+    start: Offset,
+    len: u32,
+}
+
+#[test]
+fn transparent() {
+    assert_eq!(
+        TypeId::of::<<Offset as Cuisiner>::Raw<LittleEndian>>(),
+        TypeId::of::<<u32 as Cuisiner>::Raw<LittleEndian>>()
+    );
+    assert_eq!(<Offset as Cuisiner>::ALIGN, 4);
+
+    let offset = Offset(0x0102_0304);
+    let bytes = offset.to_bytes::<LittleEndian>().unwrap();
+    assert_eq!(bytes, 0x0102_0304u32.to_bytes::<LittleEndian>().unwrap());
+    assert_eq!(Offset::from_bytes::<LittleEndian>(&bytes).unwrap(), offset);
+
+    let tagged = Tagged {
+        value: 0x0102,
+        cached: 7,
+    };
+    let bytes = tagged.to_bytes::<LittleEndian>().unwrap();
+    assert_eq!(bytes, [1, 2]);
+    assert_eq!(Tagged::from_bytes::<LittleEndian>(&bytes).unwrap(), tagged);
+
+    let bytes = [4, 3, 2, 1, 8, 0, 0, 0];
+    let view = ExtentRef::<LittleEndian>::from_bytes(&bytes).unwrap();
+    assert_eq!(view.start().unwrap(), offset);
+    assert_eq!(view.len().unwrap(), 8);
+}
@@ -0,0 +1,107 @@
+use crate::CuisinerError;
+
+/// A value which can be packed into a bit field, sharing a backing integer with neighbouring
+/// fields (`#[cuisiner(bits = N)]`).
+pub trait BitField: Sized {
+    /// Convert from the bits of the field, which have already been masked to the field's width.
+    fn from_bits(bits: u128) -> Result<Self, CuisinerError>;
+
+    /// Convert into the bits of the field. Values which don't fit within the field's width are
+    /// rejected when packed.
+    fn to_bits(&self) -> u128;
+}
+
+impl BitField for bool {
+    fn from_bits(bits: u128) -> Result<Self, CuisinerError> {
+        Ok(bits != 0)
+    }
+
+    fn to_bits(&self) -> u128 {
+        u128::from(*self)
+    }
+}
+
+macro_rules! impl_bit_field {
+    ($($ty:ty),*) => {
+        $(
+            impl BitField for $ty {
+                fn from_bits(bits: u128) -> Result<Self, CuisinerError> {
+                    Self::try_from(bits).map_err(|_| CuisinerError::BitFieldOverflow {
+                        bits: Self::BITS,
+                        value: bits.into(),
+                    })
+                }
+
+                fn to_bits(&self) -> u128 {
+                    u128::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_field!(u8, u16, u32, u64, u128);
+
+/// Mask covering the lowest `bits` bits.
+fn mask(bits: u32) -> u128 {
+    u128::MAX.checked_shr(128 - bits).unwrap_or(0)
+}
+
+/// Extract the `bits` wide field starting `shift` bits from the least significant bit.
+pub fn unpack_bits(backing: u128, bits: u32, shift: u32) -> u128 {
+    (backing >> shift) & mask(bits)
+}
+
+/// Insert `value` as the `bits` wide field starting `shift` bits from the least significant bit,
+/// rejecting it if it doesn't fit.
+pub fn pack_bits(
+    backing: &mut u128,
+    value: u128,
+    bits: u32,
+    shift: u32,
+) -> Result<(), CuisinerError> {
+    if value > mask(bits) {
+        return Err(CuisinerError::BitFieldOverflow {
+            bits,
+            value: value.into(),
+        });
+    }
+
+    *backing |= value << shift;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut backing = 0;
+        pack_bits(&mut backing, 4, 3, 5).unwrap();
+        pack_bits(&mut backing, 0b10101, 5, 0).unwrap();
+        assert_eq!(backing, 0b1001_0101);
+
+        assert_eq!(unpack_bits(backing, 3, 5), 4);
+        assert_eq!(unpack_bits(backing, 5, 0), 0b10101);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut backing = 0;
+        assert!(matches!(
+            pack_bits(&mut backing, 8, 3, 0),
+            Err(CuisinerError::BitFieldOverflow { bits: 3, .. })
+        ));
+        assert_eq!(backing, 0);
+
+        assert!(u8::from_bits(256).is_err());
+    }
+
+    #[test]
+    fn full_width() {
+        let mut backing = 0;
+        pack_bits(&mut backing, u128::MAX, 128, 0).unwrap();
+        assert_eq!(unpack_bits(backing, 128, 0), u128::MAX);
+    }
+}
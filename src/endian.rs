@@ -0,0 +1,82 @@
+use crate::{BigEndian, Cuisiner, CuisinerError, LittleEndian};
+
+/// Byte order selected at runtime, for formats which declare their endianness within the data
+/// itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Big-endian byte order ([`BigEndian`]).
+    Big,
+    /// Little-endian byte order ([`LittleEndian`]).
+    Little,
+}
+
+impl Endian {
+    /// Byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Self::Big;
+    /// Byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Self::Little;
+
+    /// Detect the byte order of the provided bytes by attempting to parse `T` from the start of
+    /// them with each byte order. `T` will generally be a magic number, such as
+    /// [`ConstU32`](crate::ConstU32). Big-endian is tried first, so it will be returned if the
+    /// value is valid in both byte orders.
+    pub fn detect<T: Cuisiner>(bytes: &[u8]) -> Option<Self> {
+        [Self::Big, Self::Little]
+            .into_iter()
+            .find(|endian| T::from_bytes_dyn(bytes, *endian).is_ok())
+    }
+
+    /// Read the provided bytes with this byte order. See [`Cuisiner::from_bytes`].
+    pub fn from_bytes<T: Cuisiner>(self, bytes: &[u8]) -> Result<T, CuisinerError> {
+        match self {
+            Self::Big => T::from_bytes::<BigEndian>(bytes),
+            Self::Little => T::from_bytes::<LittleEndian>(bytes),
+        }
+    }
+
+    /// Convert a value to bytes with this byte order. See [`Cuisiner::to_bytes`].
+    pub fn to_bytes<T: Cuisiner>(self, value: &T) -> Result<Vec<u8>, CuisinerError> {
+        match self {
+            Self::Big => value.to_bytes::<BigEndian>(),
+            Self::Little => value.to_bytes::<LittleEndian>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ConstU32;
+
+    use super::*;
+
+    type PcapMagic = ConstU32<0xa1b2c3d4>;
+
+    #[test]
+    fn detect() {
+        assert_eq!(
+            Endian::detect::<PcapMagic>(&[0xa1, 0xb2, 0xc3, 0xd4, 0x00]),
+            Some(Endian::Big)
+        );
+        assert_eq!(
+            Endian::detect::<PcapMagic>(&[0xd4, 0xc3, 0xb2, 0xa1, 0x00]),
+            Some(Endian::Little)
+        );
+        assert_eq!(Endian::detect::<PcapMagic>(&[0x00; 4]), None);
+    }
+
+    #[test]
+    fn dispatch() {
+        for endian in [Endian::Big, Endian::Little, Endian::NATIVE] {
+            let bytes = 0x1234u16.to_bytes_dyn(endian).unwrap();
+            assert_eq!(u16::from_bytes_dyn(&bytes, endian).unwrap(), 0x1234);
+        }
+
+        assert_eq!(0x1234u16.to_bytes_dyn(Endian::Big).unwrap(), [0x12, 0x34]);
+        assert_eq!(
+            0x1234u16.to_bytes_dyn(Endian::Little).unwrap(),
+            [0x34, 0x12]
+        );
+    }
+}
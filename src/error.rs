@@ -0,0 +1,236 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CuisinerError {
+    #[error("zero encountered in an unexpected location")]
+    Zero,
+
+    #[error(
+        "incorrect buffer size for serialising or deserialising (required {required}, found {found})"
+    )]
+    SizeError { required: usize, found: usize },
+
+    #[error("reader ended before a complete value was read (required {required}, found {found})")]
+    UnexpectedEof { required: usize, found: usize },
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("error when validating: {0}")]
+    Validation(String),
+
+    #[error("constant value mismatch (expected {expected}, found {found})")]
+    ConstMismatch { expected: Integer, found: Integer },
+
+    #[error("invalid discriminant for {type_name}: {value}")]
+    InvalidDiscriminant {
+        type_name: &'static str,
+        value: Integer,
+    },
+
+    #[error("undefined bits set for {type_name}: {bits}")]
+    UnknownBits {
+        type_name: &'static str,
+        bits: Integer,
+    },
+
+    #[error("value {value} does not fit in {bits} bits")]
+    BitFieldOverflow { bits: u32, value: Integer },
+
+    #[error("union member does not match its tag (tag is {tag}, member has tag {found})")]
+    UnionTagMismatch { tag: Integer, found: Integer },
+
+    #[error(transparent)]
+    Custom(Box<dyn StdError + Send + Sync>),
+
+    #[error("invalid value for `{path}` at offset {offset}")]
+    Field {
+        /// Path to the field which produced the error.
+        path: FieldPath,
+        /// Byte offset of the field, relative to the start of the outermost value.
+        offset: usize,
+        /// Error produced by the field.
+        source: Box<CuisinerError>,
+    },
+}
+
+impl CuisinerError {
+    /// Create a [`CuisinerError::SizeError`] for a buffer of length `found` which was expected to
+    /// hold a `T`.
+    pub(crate) fn size_error<T>(found: usize) -> Self {
+        Self::SizeError {
+            required: std::mem::size_of::<T>(),
+            found,
+        }
+    }
+
+    /// Wrap any error (or message) as a [`CuisinerError::Custom`].
+    pub fn custom(error: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        Self::Custom(error.into())
+    }
+
+    /// Attach the location of a field to this error. `offset` is the byte offset of the field
+    /// within the raw representation of `container`. If the error already has a location, it will
+    /// be nested within this field.
+    pub fn in_field(self, container: &'static str, field: &'static str, offset: usize) -> Self {
+        self.nest(
+            [PathSegment::Type(container), PathSegment::Field(field)],
+            offset,
+        )
+    }
+
+    /// Attach the location of an item within an array to this error. `offset` is the byte offset
+    /// of the item within the array.
+    pub fn in_index(self, index: usize, offset: usize) -> Self {
+        self.nest([PathSegment::Index(index)], offset)
+    }
+
+    /// Path to the field that produced this error, if known.
+    pub fn path(&self) -> Option<&FieldPath> {
+        match self {
+            Self::Field { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Byte offset of the field that produced this error, if known.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::Field { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// Underlying error, without any location information.
+    pub fn root_cause(&self) -> &CuisinerError {
+        match self {
+            Self::Field { source, .. } => source.root_cause(),
+            e => e,
+        }
+    }
+
+    fn nest(self, segments: impl IntoIterator<Item = PathSegment>, offset: usize) -> Self {
+        let (inner_path, inner_offset, source) = match self {
+            Self::Field {
+                path,
+                offset,
+                source,
+            } => (path.0, offset, source),
+            e => (Vec::new(), 0, Box::new(e)),
+        };
+
+        Self::Field {
+            path: FieldPath(
+                segments
+                    .into_iter()
+                    .chain(
+                        inner_path
+                            .into_iter()
+                            // The outer field replaces the name of the inner type.
+                            .skip_while(|segment| matches!(segment, PathSegment::Type(_))),
+                    )
+                    .collect(),
+            ),
+            offset: offset + inner_offset,
+            source,
+        }
+    }
+}
+
+/// Path to a field within a value, such as `SqliteHeader.max_payload_fraction`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldPath(Vec<PathSegment>);
+
+impl FieldPath {
+    /// Each segment of the path, starting from the outermost value.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Type(name) => write!(f, "{name}")?,
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Segment of a [`FieldPath`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// Name of the type containing the following segments.
+    Type(&'static str),
+    /// Name of a field.
+    Field(&'static str),
+    /// Index within an array.
+    Index(usize),
+}
+
+/// Integer value of any size or signedness, used to report numeric values within errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Integer {
+    Unsigned(u128),
+    Signed(i128),
+}
+
+impl Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned(n) => n.fmt(f),
+            Self::Signed(n) => n.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_integer_from {
+    ($variant:ident: $inner:ty => $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Integer {
+                fn from(n: $ty) -> Self {
+                    Self::$variant(n as $inner)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_from!(Unsigned: u128 => u8, u16, u32, u64, u128, usize);
+impl_integer_from!(Signed: i128 => i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nested_field() {
+        let e = CuisinerError::Zero
+            .in_field("Inner", "value", 2)
+            .in_index(3, 12)
+            .in_field("Outer", "items", 4);
+
+        assert_eq!(e.path().unwrap().to_string(), "Outer.items[3].value");
+        assert_eq!(e.offset(), Some(18));
+        assert!(matches!(e.root_cause(), CuisinerError::Zero));
+        assert_eq!(
+            e.to_string(),
+            "invalid value for `Outer.items[3].value` at offset 18"
+        );
+        assert_eq!(
+            e.source().unwrap().to_string(),
+            "zero encountered in an unexpected location"
+        );
+    }
+}
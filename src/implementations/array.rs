@@ -3,33 +3,72 @@ use crate::{ByteOrder, Cuisiner, CuisinerError};
 impl<const N: usize, T: Cuisiner> Cuisiner for [T; N] {
     type Raw<B: ByteOrder> = [T::Raw<B>; N];
 
+    const ALIGN: usize = T::ALIGN;
+
     fn try_from_raw<B: ByteOrder>(raw: Self::Raw<B>) -> Result<Self, CuisinerError> {
-        // SAFETY: Const parameters ensure that every zeroed item will be overwritten before being
-        // read.
-        let mut out = unsafe { std::mem::zeroed::<Self>() };
+        try_map::<_, _, N, T::Raw<B>>(raw, T::try_from_raw)
+    }
 
-        for (raw, out) in raw.into_iter().zip(&mut out) {
-            *out = T::try_from_raw(raw)?;
-        }
+    fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
+        try_map::<_, _, N, T::Raw<B>>(self, T::try_to_raw)
+    }
 
-        Ok(out)
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
+        try_map::<_, _, N, T::Raw<B>>(self.each_ref(), T::try_to_raw_ref)
     }
 
-    fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
-        // SAFETY: Const parameters ensure that every zeroed item will be overwritten before being
-        // read.
-        let mut out = unsafe { std::mem::zeroed::<Self::Raw<B>>() };
+    fn validate_raw<B: ByteOrder>(raw: &Self::Raw<B>, errors: &mut Vec<CuisinerError>) {
+        for (i, raw) in raw.iter().enumerate() {
+            let mut item_errors = Vec::new();
+            T::validate_raw::<B>(raw, &mut item_errors);
+            errors.extend(
+                item_errors
+                    .into_iter()
+                    .map(|e| e.in_index(i, i * std::mem::size_of::<T::Raw<B>>())),
+            );
+        }
+    }
+}
+
+/// Apply a fallible conversion to each item of an array, without requiring any of the items to
+/// have a valid 'zero' value. Conversion stops at the first error, and any items that were already
+/// converted are dropped. Errors are annotated with the index and offset of the item, where `R` is
+/// the raw type of each item.
+fn try_map<T, U, const N: usize, R>(
+    array: [T; N],
+    mut f: impl FnMut(T) -> Result<U, CuisinerError>,
+) -> Result<[U; N], CuisinerError> {
+    let mut error = None;
+    let mut index = 0;
 
-        for (raw, out) in self.into_iter().zip(&mut out) {
-            *out = T::try_to_raw(raw)?;
+    let out = array.map(|item| {
+        if error.is_some() {
+            return None;
         }
 
-        Ok(out)
+        let i = index;
+        index += 1;
+
+        match f(item) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                error = Some(e.in_index(i, i * std::mem::size_of::<R>()));
+                None
+            }
+        }
+    });
+
+    if let Some(e) = error {
+        return Err(e);
     }
+
+    Ok(out.map(|item| item.expect("every item converted successfully")))
 }
 
 #[cfg(test)]
 mod test {
+    use std::{cell::Cell, num::NonZero};
+
     use crate::BigEndian;
 
     use super::*;
@@ -41,4 +80,69 @@ mod test {
         let parsed = <[u32; 3]>::from_bytes::<BigEndian>(&bytes).unwrap();
         assert_eq!(arr, parsed);
     }
+
+    #[test]
+    fn non_zero_array() {
+        let arr = [1, 2, 3].map(|n| NonZero::new(n).unwrap());
+        let bytes = arr.to_bytes::<BigEndian>().unwrap();
+        assert_eq!(bytes, [0, 1, 0, 2, 0, 3]);
+        assert_eq!(
+            <[NonZero<u16>; 3]>::from_bytes::<BigEndian>(&bytes).unwrap(),
+            arr
+        );
+
+        let e = <[NonZero<u16>; 3]>::from_bytes::<BigEndian>(&[0, 1, 0, 0, 0, 3]).unwrap_err();
+        assert_eq!(e.path().unwrap().to_string(), "[1]");
+        assert_eq!(e.offset(), Some(2));
+        assert!(matches!(e.root_cause(), CuisinerError::Zero));
+    }
+
+    #[test]
+    fn validate_every_item() {
+        let errors =
+            <[NonZero<u16>; 3]>::from_bytes_report::<BigEndian>(&[0, 0, 0, 1, 0, 0]).unwrap_err();
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.path().unwrap().to_string(), e.offset().unwrap()))
+                .collect::<Vec<_>>(),
+            [("[0]".to_string(), 0), ("[2]".to_string(), 4)]
+        );
+    }
+
+    thread_local! {
+        static DROPPED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Non-zero value which counts how many times it has been dropped.
+    struct Tracked(NonZero<u8>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            DROPPED.set(DROPPED.get() + 1);
+        }
+    }
+
+    impl Cuisiner for Tracked {
+        type Raw<B: ByteOrder> = u8;
+
+        fn try_from_raw<B: ByteOrder>(raw: Self::Raw<B>) -> Result<Self, CuisinerError> {
+            Ok(Self(NonZero::try_from_raw::<B>(raw)?))
+        }
+
+        fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
+            self.try_to_raw_ref::<B>()
+        }
+
+        fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
+            self.0.try_to_raw_ref::<B>()
+        }
+    }
+
+    #[test]
+    fn drop_converted_on_error() {
+        DROPPED.set(0);
+        assert!(<[Tracked; 4]>::from_bytes::<BigEndian>(&[1, 2, 0, 4]).is_err());
+        assert_eq!(DROPPED.get(), 2);
+    }
 }
@@ -13,7 +13,11 @@ macro_rules! impl_identity {
             }
 
             fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
-                Ok(self)
+                self.try_to_raw_ref::<B>()
+            }
+
+            fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
+                Ok(*self)
             }
         }
     };
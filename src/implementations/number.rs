@@ -4,16 +4,22 @@ macro_rules! impl_number {
         impl $crate::Cuisiner for $ty {
             type Raw<B: $crate::ByteOrder> = $raw;
 
+            const ALIGN: usize = core::mem::size_of::<$ty>();
+
             fn try_from_raw<B: $crate::ByteOrder>(
                 raw: Self::Raw<B>,
             ) -> Result<Self, $crate::CuisinerError> {
                 Ok(raw.get())
             }
 
-            fn try_to_raw<B: $crate::ByteOrder>(
-                self,
+            fn try_to_raw<B: $crate::ByteOrder>(self) -> Result<Self::Raw<B>, $crate::CuisinerError> {
+                self.try_to_raw_ref::<B>()
+            }
+
+            fn try_to_raw_ref<B: $crate::ByteOrder>(
+                &self,
             ) -> Result<Self::Raw<B>, $crate::CuisinerError> {
-                Ok(Self::Raw::<B>::from(self))
+                Ok(Self::Raw::<B>::from(*self))
             }
         }
     };
@@ -24,6 +30,8 @@ macro_rules! impl_number {
         impl $crate::Cuisiner for Option<core::num::NonZero<$ty>> {
             type Raw<B: $crate::ByteOrder> = $raw;
 
+            const ALIGN: usize = core::mem::size_of::<$ty>();
+
             fn try_from_raw<B: $crate::ByteOrder>(
                 raw: Self::Raw<B>,
             ) -> Result<Self, $crate::CuisinerError> {
@@ -36,13 +44,19 @@ macro_rules! impl_number {
             }
 
             fn try_to_raw<B: $crate::ByteOrder>(self) -> Result<Self::Raw<B>, $crate::CuisinerError> {
-                <$ty as $crate::Cuisiner>::try_to_raw::<B>(self.map(|n| n.get()).unwrap_or(0))
+                self.try_to_raw_ref::<B>()
+            }
+
+            fn try_to_raw_ref<B: $crate::ByteOrder>(&self) -> Result<Self::Raw<B>, $crate::CuisinerError> {
+                <$ty as $crate::Cuisiner>::try_to_raw_ref::<B>(&self.map(|n| n.get()).unwrap_or(0))
             }
         }
 
         impl $crate::Cuisiner for core::num::NonZero<$ty> {
             type Raw<B: $crate::ByteOrder> = $raw;
 
+            const ALIGN: usize = core::mem::size_of::<$ty>();
+
             fn try_from_raw<B: $crate::ByteOrder>(
                 raw: Self::Raw<B>,
             ) -> Result<Self, $crate::CuisinerError> {
@@ -51,7 +65,11 @@ macro_rules! impl_number {
             }
 
             fn try_to_raw<B: $crate::ByteOrder>(self) -> Result<Self::Raw<B>, $crate::CuisinerError> {
-                <$ty as $crate::Cuisiner>::try_to_raw::<B>(self.get())
+                self.try_to_raw_ref::<B>()
+            }
+
+            fn try_to_raw_ref<B: $crate::ByteOrder>(&self) -> Result<Self::Raw<B>, $crate::CuisinerError> {
+                <$ty as $crate::Cuisiner>::try_to_raw_ref::<B>(&self.get())
             }
         }
     };
@@ -60,16 +78,22 @@ macro_rules! impl_number {
         impl $crate::Cuisiner for $ty {
             type Raw<B: $crate::ByteOrder> = $ty;
 
+            const ALIGN: usize = core::mem::size_of::<$ty>();
+
             fn try_from_raw<B: $crate::ByteOrder>(
                 raw: Self::Raw<B>,
             ) -> Result<Self, $crate::CuisinerError> {
                 Ok(raw)
             }
 
-            fn try_to_raw<B: $crate::ByteOrder>(
-                self,
+            fn try_to_raw<B: $crate::ByteOrder>(self) -> Result<Self::Raw<B>, $crate::CuisinerError> {
+                self.try_to_raw_ref::<B>()
+            }
+
+            fn try_to_raw_ref<B: $crate::ByteOrder>(
+                &self,
             ) -> Result<Self::Raw<B>, $crate::CuisinerError> {
-                Ok(self)
+                Ok(*self)
             }
         }
 
@@ -106,7 +130,7 @@ mod test {
 
         fn test(&self) {
             // Test BE
-            let be_bytes = <Self as Cuisiner>::to_bytes::<BigEndian>(self.clone()).unwrap();
+            let be_bytes = <Self as Cuisiner>::to_bytes::<BigEndian>(self).unwrap();
             let be_native_bytes = self.be_bytes();
             let be_n = <Self as Cuisiner>::from_bytes::<BigEndian>(&be_bytes).unwrap();
 
@@ -114,7 +138,7 @@ mod test {
             assert_eq!(&be_n, self);
 
             // Test LE
-            let le_bytes = <Self as Cuisiner>::to_bytes::<LittleEndian>(self.clone()).unwrap();
+            let le_bytes = <Self as Cuisiner>::to_bytes::<LittleEndian>(self).unwrap();
             let le_native_bytes = self.le_bytes();
             let le_n = <Self as Cuisiner>::from_bytes::<LittleEndian>(&le_bytes).unwrap();
 
@@ -0,0 +1,95 @@
+use std::io::{ErrorKind, Read, Write};
+
+use zerocopy::{FromBytes, IntoBytes};
+
+use crate::{ByteOrder, Cuisiner, CuisinerError};
+
+/// Extension trait to read [`Cuisiner`] values directly from any [`Read`] implementation.
+pub trait CuisinerRead: Read {
+    /// Read a value with the provided byte order.
+    fn read_cuisiner<T: Cuisiner, B: ByteOrder>(&mut self) -> Result<T, CuisinerError> {
+        T::read_from::<B, _>(self)
+    }
+}
+
+impl<R: Read + ?Sized> CuisinerRead for R {}
+
+/// Extension trait to write [`Cuisiner`] values directly to any [`Write`] implementation.
+pub trait CuisinerWrite: Write {
+    /// Write a value with the provided byte order.
+    fn write_cuisiner<T: Cuisiner, B: ByteOrder>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), CuisinerError> {
+        value.write_to::<B, _>(self)
+    }
+}
+
+impl<W: Write + ?Sized> CuisinerWrite for W {}
+
+/// Read exactly `size_of::<T>()` bytes from the reader into a raw value. Reaching the end of the
+/// reader early will produce [`CuisinerError::UnexpectedEof`].
+pub(crate) fn read_raw<T: FromBytes + IntoBytes, R: Read>(
+    mut reader: R,
+) -> Result<T, CuisinerError> {
+    let mut raw = T::new_zeroed();
+    let buf = raw.as_mut_bytes();
+
+    let mut found = 0;
+    while found < buf.len() {
+        match reader.read(&mut buf[found..]) {
+            Ok(0) => {
+                return Err(CuisinerError::UnexpectedEof {
+                    required: buf.len(),
+                    found,
+                });
+            }
+            Ok(n) => found += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use crate::{BigEndian, LittleEndian};
+
+    use super::*;
+
+    #[test]
+    fn read_write_round_trip() {
+        let mut buf = Vec::new();
+        buf.write_cuisiner::<_, BigEndian>(&0x1234_5678u32).unwrap();
+        buf.write_cuisiner::<_, LittleEndian>(&[0xabcdu16, 0xef01])
+            .unwrap();
+        assert_eq!(buf, [0x12, 0x34, 0x56, 0x78, 0xcd, 0xab, 0x01, 0xef]);
+
+        let mut reader = Cursor::new(buf);
+        assert_eq!(
+            reader.read_cuisiner::<u32, BigEndian>().unwrap(),
+            0x1234_5678
+        );
+        assert_eq!(
+            reader.read_cuisiner::<[u16; 2], LittleEndian>().unwrap(),
+            [0xabcd, 0xef01]
+        );
+    }
+
+    #[test]
+    fn short_read() {
+        let err =
+            <u32 as Cuisiner>::read_from::<BigEndian, _>([0x12, 0x34].as_slice()).unwrap_err();
+        assert!(matches!(
+            err,
+            CuisinerError::UnexpectedEof {
+                required: 4,
+                found: 2
+            }
+        ));
+    }
+}
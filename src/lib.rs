@@ -1,52 +1,266 @@
 #![doc = include_str!("../README.md")]
 
+mod bits;
+mod endian;
+mod error;
 mod implementations;
+mod io;
+mod union;
 mod util;
 
-use thiserror::Error;
+use std::io::{Read, Write};
+
 use zerocopy::{FromBytes, Immutable, IntoBytes, Unaligned};
 
 pub use assert_layout::assert_layout;
 pub use cuisiner_derive::Cuisiner;
 pub use zerocopy::{self, BigEndian, ByteOrder, LittleEndian, NativeEndian, NetworkEndian};
 
-pub use self::util::*;
+pub use self::{
+    bits::BitField,
+    endian::Endian,
+    error::{CuisinerError, FieldPath, Integer, PathSegment},
+    io::{CuisinerRead, CuisinerWrite},
+    union::CuisinerUnion,
+    util::*,
+};
 
 pub trait Cuisiner: Sized {
     type Raw<B: ByteOrder>: FromBytes + IntoBytes + Immutable + Unaligned;
 
+    /// Alignment that a C compiler would give this value, used to insert padding within
+    /// containers marked `#[cuisiner(c_alignment)]`. The raw value itself is always unaligned.
+    /// Numbers are aligned to their size rather than the alignment of the compile target, so that
+    /// the layout is the same everywhere.
+    const ALIGN: usize = 1;
+
     /// Attempt to convert this value from a raw value.
     fn try_from_raw<B: ByteOrder>(raw: Self::Raw<B>) -> Result<Self, CuisinerError>;
 
     /// Attempt to convert this value into the raw value.
     fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError>;
 
-    /// Read the provided bytes and attempt to parse out the type.
+    /// Attempt to convert a reference to this value into the raw value, without consuming it. The
+    /// methods which serialise a value (such as [`Cuisiner::to_bytes`]) go through this. For a
+    /// [`Clone`] type, it can be as simple as `self.clone().try_to_raw()`.
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError>;
+
+    /// Validate the raw value, collecting every problem into `errors` rather than stopping at the
+    /// first. Types composed of other values should validate each of them, so that all errors are
+    /// reported.
+    fn validate_raw<B: ByteOrder>(raw: &Self::Raw<B>, errors: &mut Vec<CuisinerError>) {
+        if let Err(e) = Self::try_from_raw::<B>(__private::copy_raw(raw)) {
+            errors.push(e);
+        }
+    }
+
+    /// Read the provided bytes and attempt to parse out the type. Any trailing bytes are ignored.
     fn from_bytes<B: ByteOrder>(bytes: &[u8]) -> Result<Self, CuisinerError> {
-        let (raw, _) =
-            Self::Raw::<B>::read_from_prefix(bytes).map_err(|_| CuisinerError::SizeError {
-                required: std::mem::size_of::<Self::Raw<B>>(),
-                found: bytes.len(),
-            })?;
+        let (value, _) = Self::read_from_prefix::<B>(bytes)?;
+        Ok(value)
+    }
+
+    /// Read the provided bytes like [`Cuisiner::from_bytes`], but report every validation problem
+    /// if the value is invalid. See [`Cuisiner::validate_raw`].
+    fn from_bytes_report<B: ByteOrder>(bytes: &[u8]) -> Result<Self, Vec<CuisinerError>> {
+        let (raw, _) = Self::Raw::<B>::read_from_prefix(bytes)
+            .map_err(|_| vec![CuisinerError::size_error::<Self::Raw<B>>(bytes.len())])?;
+
+        let mut errors = Vec::new();
+        Self::validate_raw::<B>(&raw, &mut errors);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Self::try_from_raw(raw).map_err(|e| vec![e])
+    }
+
+    /// Read the provided bytes with a byte order selected at runtime. See [`Endian::detect`] for
+    /// determining the byte order from a magic number.
+    fn from_bytes_dyn(bytes: &[u8], endian: Endian) -> Result<Self, CuisinerError> {
+        endian.from_bytes(bytes)
+    }
+
+    /// Attempt to parse the type from exactly the provided bytes. Produces
+    /// [`CuisinerError::SizeError`] if the length of the bytes doesn't match the raw
+    /// representation.
+    fn read_from_bytes<B: ByteOrder>(bytes: &[u8]) -> Result<Self, CuisinerError> {
+        let raw = Self::Raw::<B>::read_from_bytes(bytes)
+            .map_err(|_| CuisinerError::size_error::<Self::Raw<B>>(bytes.len()))?;
         Self::try_from_raw(raw)
     }
 
+    /// Attempt to parse the type from the start of the provided bytes, returning the remaining
+    /// bytes.
+    fn read_from_prefix<B: ByteOrder>(bytes: &[u8]) -> Result<(Self, &[u8]), CuisinerError> {
+        let (raw, rest) = Self::Raw::<B>::read_from_prefix(bytes)
+            .map_err(|_| CuisinerError::size_error::<Self::Raw<B>>(bytes.len()))?;
+        Ok((Self::try_from_raw(raw)?, rest))
+    }
+
+    /// Attempt to parse the type from the end of the provided bytes, returning the preceding
+    /// bytes.
+    fn read_from_suffix<B: ByteOrder>(bytes: &[u8]) -> Result<(&[u8], Self), CuisinerError> {
+        let (rest, raw) = Self::Raw::<B>::read_from_suffix(bytes)
+            .map_err(|_| CuisinerError::size_error::<Self::Raw<B>>(bytes.len()))?;
+        Ok((rest, Self::try_from_raw(raw)?))
+    }
+
     /// Convert a value to it's raw representation.
-    fn to_bytes<B: ByteOrder>(self) -> Result<Vec<u8>, CuisinerError> {
-        Ok(self.try_to_raw::<B>()?.as_bytes().to_vec())
+    fn to_bytes<B: ByteOrder>(&self) -> Result<Vec<u8>, CuisinerError> {
+        Ok(self.try_to_raw_ref::<B>()?.as_bytes().to_vec())
+    }
+
+    /// Convert a value to it's raw representation with a byte order selected at runtime.
+    fn to_bytes_dyn(&self, endian: Endian) -> Result<Vec<u8>, CuisinerError> {
+        endian.to_bytes(self)
+    }
+
+    /// Convert a value to it's raw representation, and write it into the start of the provided
+    /// buffer without allocating. Returns the number of bytes written.
+    fn write_into<B: ByteOrder>(&self, bytes: &mut [u8]) -> Result<usize, CuisinerError> {
+        self.write_to_prefix::<B>(bytes)?;
+        Ok(std::mem::size_of::<Self::Raw<B>>())
+    }
+
+    /// Convert a value to it's raw representation, and append it to the provided [`Vec`]. Returns
+    /// the number of bytes appended.
+    fn extend_vec<B: ByteOrder>(&self, vec: &mut Vec<u8>) -> Result<usize, CuisinerError> {
+        let raw = self.try_to_raw_ref::<B>()?;
+        let bytes = raw.as_bytes();
+        vec.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    /// Convert a value to it's raw representation, and write it to the start of the provided
+    /// bytes.
+    fn write_to_prefix<B: ByteOrder>(&self, bytes: &mut [u8]) -> Result<(), CuisinerError> {
+        let len = bytes.len();
+        self.try_to_raw_ref::<B>()?
+            .write_to_prefix(bytes)
+            .map_err(|_| CuisinerError::size_error::<Self::Raw<B>>(len))
+    }
+
+    /// Convert a value to it's raw representation, and write it to the end of the provided bytes.
+    fn write_to_suffix<B: ByteOrder>(&self, bytes: &mut [u8]) -> Result<(), CuisinerError> {
+        let len = bytes.len();
+        self.try_to_raw_ref::<B>()?
+            .write_to_suffix(bytes)
+            .map_err(|_| CuisinerError::size_error::<Self::Raw<B>>(len))
+    }
+
+    /// Read exactly enough bytes for the raw representation from the reader, and attempt to parse
+    /// out the type.
+    fn read_from<B: ByteOrder, R: Read>(reader: R) -> Result<Self, CuisinerError> {
+        Self::try_from_raw(io::read_raw::<Self::Raw<B>, R>(reader)?)
+    }
+
+    /// Convert a value to it's raw representation, and write it to the writer.
+    fn write_to<B: ByteOrder, W: Write>(&self, mut writer: W) -> Result<(), CuisinerError> {
+        writer.write_all(self.try_to_raw_ref::<B>()?.as_bytes())?;
+        Ok(())
     }
 }
 
-#[derive(Debug, Error)]
-pub enum CuisinerError {
-    #[error("zero encountered in an unexpected location")]
-    Zero,
+/// Implementation details used by the derive macro. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use zerocopy::{ByteOrder, FromBytes, Immutable, IntoBytes, NativeEndian};
+
+    use crate::{Cuisiner, CuisinerError, CuisinerUnion};
+
+    pub use crate::bits::{pack_bits, unpack_bits};
+
+    /// Copy a raw value out from behind a reference.
+    pub fn copy_raw<T: FromBytes + IntoBytes + Immutable>(raw: &T) -> T {
+        T::read_from_bytes(raw.as_bytes()).expect("source is same size as the value")
+    }
+
+    /// Largest of the provided sizes, usable in const contexts.
+    pub const fn max_size(sizes: &[usize]) -> usize {
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
+    }
+
+    /// Bytes of padding required to place a field at `offset`, after a field ending at `end`.
+    pub const fn padding(offset: usize, end: usize) -> usize {
+        assert!(offset >= end, "field offset overlaps the previous field");
+        offset - end
+    }
+
+    /// Bytes of padding required to align a field to `align`, after a field ending at `end`.
+    pub const fn align_padding(end: usize, align: usize) -> usize {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        end.next_multiple_of(align) - end
+    }
+
+    /// Bytes of padding required after fields ending at `end` to reach the container's `size`.
+    pub const fn tail_padding(size: usize, end: usize) -> usize {
+        assert!(size >= end, "fields exceed the size of the container");
+        size - end
+    }
 
-    #[error(
-        "incorrect buffer size for serialising or deserialising (required {required}, found {found})"
-    )]
-    SizeError { required: usize, found: usize },
+    /// Read a variant's payload from the start of an enum body.
+    pub fn read_payload<T: FromBytes>(body: &[u8]) -> T {
+        let (payload, _) = T::read_from_prefix(body).expect("body fits every payload");
+        payload
+    }
+
+    /// Write a variant's payload to the start of an enum body.
+    pub fn write_payload<T: IntoBytes + Immutable>(payload: &T, body: &mut [u8]) {
+        payload
+            .write_to_prefix(body)
+            .expect("body fits every payload");
+    }
 
-    #[error("error when validating: {0}")]
-    Validation(String),
+    /// Integer selecting a member of the union `U`, converted from the value of the tag field
+    /// through its raw representation. This allows enums deriving [`Cuisiner`] to be used as the tag,
+    /// provided that they share the integer representation of the union.
+    pub fn union_tag<U, T>(tag: &T) -> Result<U::Tag, CuisinerError>
+    where
+        U: CuisinerUnion,
+        U::Tag: Cuisiner<Raw<NativeEndian> = T::Raw<NativeEndian>>,
+        T: Cuisiner,
+    {
+        U::Tag::try_from_raw::<NativeEndian>(tag.try_to_raw_ref::<NativeEndian>()?)
+    }
+
+    /// Convert a union member into its raw value, ensuring that it is the member selected by the
+    /// tag field holding `tag`.
+    pub fn union_to_raw<U, B, T>(value: &U, tag: &T) -> Result<U::Raw<B>, CuisinerError>
+    where
+        U: CuisinerUnion,
+        U::Tag: Cuisiner<Raw<NativeEndian> = T::Raw<NativeEndian>>,
+        B: ByteOrder,
+        T: Cuisiner,
+    {
+        let tag = union_tag::<U, T>(tag)?;
+        let found = value.tag();
+        if found != tag {
+            return Err(CuisinerError::UnionTagMismatch {
+                tag: tag.into(),
+                found: found.into(),
+            });
+        }
+
+        value.try_to_raw_ref::<B>()
+    }
+
+    /// Pass the raw value of `U` through as the raw value of `T`, requiring both types to share a
+    /// raw representation.
+    pub fn into_raw<T, U, B>(raw: U::Raw<B>) -> T::Raw<B>
+    where
+        T: Cuisiner,
+        U: Cuisiner<Raw<B> = T::Raw<B>>,
+        B: ByteOrder,
+    {
+        raw
+    }
 }
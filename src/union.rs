@@ -0,0 +1,29 @@
+use zerocopy::{ByteOrder, FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+use crate::{CuisinerError, Integer};
+
+/// A C-style `union`, where the active member is selected by a tag stored outside of the union
+/// (typically a sibling field, marked with `#[cuisiner(union_tag = ...)]`).
+///
+/// The raw representation is only as large as the largest member, and contains no tag of its own.
+pub trait CuisinerUnion: Sized {
+    type Raw<B: ByteOrder>: FromBytes + IntoBytes + Immutable + KnownLayout + Unaligned;
+
+    /// Value used to select the active member.
+    type Tag: Copy + PartialEq + Into<Integer>;
+
+    /// Alignment that a C compiler would give this union, which is the largest alignment of its
+    /// members. See [`Cuisiner::ALIGN`](crate::Cuisiner::ALIGN).
+    const ALIGN: usize = 1;
+
+    /// Attempt to convert the member selected by `tag` from a raw value.
+    fn try_from_raw<B: ByteOrder>(tag: Self::Tag, raw: Self::Raw<B>)
+    -> Result<Self, CuisinerError>;
+
+    /// Tag selecting the member held by this value.
+    fn tag(&self) -> Self::Tag;
+
+    /// Attempt to convert the held member into the raw value. Any bytes not used by the member are
+    /// zeroed.
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError>;
+}
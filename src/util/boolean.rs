@@ -16,7 +16,17 @@ impl<const N: usize> Cuisiner for ByteBoolean<N> {
     }
 
     fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
-        Ok([if *self { 0xff } else { 0x00 }; N])
+        self.try_to_raw_ref::<B>()
+    }
+
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
+        Ok([if **self { 0xff } else { 0x00 }; N])
+    }
+}
+
+impl<const N: usize> From<bool> for ByteBoolean<N> {
+    fn from(value: bool) -> Self {
+        Self(value)
     }
 }
 
@@ -10,18 +10,25 @@ macro_rules! impl_const_value {
         impl<const N: $ty> Cuisiner for $ident<N> {
             type Raw<B: ByteOrder> = $raw_ty;
 
+            const ALIGN: usize = core::mem::size_of::<$ty>();
+
             fn try_from_raw<B: ByteOrder>($raw: Self::Raw<B>) -> Result<Self, CuisinerError> {
                 let raw = $raw_to_value;
                 if raw != N {
-                    return Err(CuisinerError::Validation(format!(
-                        "expected {N}, found {raw}"
-                    )));
+                    return Err(CuisinerError::ConstMismatch {
+                        expected: N.into(),
+                        found: raw.into(),
+                    });
                 }
 
                 Ok(Self)
             }
 
             fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
+                self.try_to_raw_ref::<B>()
+            }
+
+            fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
                 let $value = N;
                 Ok($value_to_raw)
             }
@@ -12,6 +12,10 @@ impl<const N: usize> Cuisiner for Reserved<N> {
     }
 
     fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
+        self.try_to_raw_ref::<B>()
+    }
+
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
         Ok([0; N])
     }
 }
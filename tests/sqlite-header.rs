@@ -1,14 +1,15 @@
 use std::num::NonZero;
 
-use cuisiner::{ByteBoolean, ByteOrder, ConstU8, Cuisiner, CuisinerError, Reserved};
+use cuisiner::{
+    BigEndian, ByteBoolean, ByteOrder, ConstU8, Cuisiner, CuisinerError, Integer, Reserved,
+};
 use zerocopy::{U16, U32};
 
-#[allow(dead_code)]
 const HEADER_SIZE: usize = 100;
 const HEADER_STRING_LEN: usize = 16;
 
 #[derive(Clone, Cuisiner, Debug)]
-#[cuisiner(assert(size = HEADER_SIZE))]
+#[cuisiner(assert(size = HEADER_SIZE), view, view_mut)]
 struct SqliteHeader {
     #[cuisiner(assert(offset = 0, size = HEADER_STRING_LEN))]
     header_string: HeaderString,
@@ -79,6 +80,10 @@ impl Cuisiner for HeaderString {
     fn try_to_raw<B: ByteOrder>(self) -> Result<Self::Raw<B>, CuisinerError> {
         Ok(Self::BYTES)
     }
+
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
+        self.clone().try_to_raw::<B>()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -121,6 +126,10 @@ impl Cuisiner for PageSize {
             }
         }))
     }
+
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
+        self.clone().try_to_raw::<B>()
+    }
 }
 
 #[derive(Clone, Cuisiner, Debug)]
@@ -170,4 +179,150 @@ impl Cuisiner for VersionNumber {
             self.major as u32 * 1_000_000 + self.minor as u32 * 1_000 + self.patch as u32,
         ))
     }
+
+    fn try_to_raw_ref<B: ByteOrder>(&self) -> Result<Self::Raw<B>, CuisinerError> {
+        self.clone().try_to_raw::<B>()
+    }
+}
+
+fn header() -> SqliteHeader {
+    SqliteHeader {
+        header_string: HeaderString,
+        page_size: PageSize(4096),
+        file_format_write_version: FileFormatVersion::Wal,
+        file_format_read_version: FileFormatVersion::Wal,
+        page_end_padding: None,
+        max_payload_fraction: ConstU8,
+        min_payload_fraction: ConstU8,
+        leaf_payload_fraction: ConstU8,
+        file_change_counter: 3,
+        page_count: 12,
+        freelist_trunk_page: 0,
+        freelist_page_count: 0,
+        schema_cookie: 5,
+        schema_format: SchemaFormat::V4,
+        default_page_cache_size: 0,
+        largest_root_btree_page: None,
+        text_encoding: TextEncoding::Utf8,
+        user_version: 0,
+        incremental_vacuum_mode: ByteBoolean::from(false),
+        application_id: 0,
+        _reserved: Reserved,
+        version_valid_for: 3,
+        sqlite_version_number: VersionNumber {
+            major: 3,
+            minor: 45,
+            patch: 1,
+        },
+    }
+}
+
+#[test]
+fn read_write() {
+    let mut file = Vec::new();
+    header().write_to::<BigEndian, _>(&mut file).unwrap();
+    assert_eq!(file.len(), HEADER_SIZE);
+    assert_eq!(&file[..HEADER_STRING_LEN], b"SQLite format 3\0");
+
+    let parsed = SqliteHeader::read_from::<BigEndian, _>(file.as_slice()).unwrap();
+    assert_eq!(parsed.page_size.0, 4096);
+    assert_eq!(parsed.file_change_counter, 3);
+    assert_eq!(parsed.sqlite_version_number.minor, 45);
+}
+
+#[test]
+fn write_repeatedly() {
+    let header = header();
+
+    let mut file = vec![0; HEADER_SIZE * 2];
+    header.write_to_prefix::<BigEndian>(&mut file).unwrap();
+    header.write_to_suffix::<BigEndian>(&mut file).unwrap();
+
+    assert_eq!(&file[..HEADER_SIZE], &file[HEADER_SIZE..]);
+    assert_eq!(
+        header.to_bytes::<BigEndian>().unwrap(),
+        &file[..HEADER_SIZE]
+    );
+}
+
+#[test]
+fn view() {
+    let mut file = header().to_bytes::<BigEndian>().unwrap();
+    file.extend_from_slice(&[0xff; 32]);
+
+    let (view, rest) = SqliteHeaderRef::<BigEndian>::from_prefix(&file).unwrap();
+    assert_eq!(rest.len(), 32);
+    assert_eq!(view.page_size().unwrap().0, 4096);
+    assert_eq!(view.page_count().unwrap(), 12);
+    assert_eq!(view.to_value().unwrap().schema_cookie, 5);
+
+    // Only the accessed field is validated.
+    file[21] = 0;
+    let view = SqliteHeaderRef::<BigEndian>::from_bytes(&file[..HEADER_SIZE]).unwrap();
+    assert!(view.max_payload_fraction().is_err());
+    assert_eq!(view.file_change_counter().unwrap(), 3);
+
+    assert!(SqliteHeaderRef::<BigEndian>::from_bytes(&file).is_err());
+}
+
+#[test]
+fn view_mut() {
+    let mut file = header().to_bytes::<BigEndian>().unwrap();
+    let original = file.clone();
+
+    let mut view = SqliteHeaderMut::<BigEndian>::from_bytes(&mut file).unwrap();
+    let counter = view.file_change_counter().unwrap();
+    view.set_file_change_counter(counter + 1).unwrap();
+    assert_eq!(view.file_change_counter().unwrap(), 4);
+
+    // Validation still runs when writing.
+    assert!(view.set_page_size(PageSize(3)).is_err());
+
+    // Only the bytes of the field were modified.
+    assert_eq!(&file[24..28], [0, 0, 0, 4]);
+    assert_eq!(file[..24], original[..24]);
+    assert_eq!(file[28..], original[28..]);
+}
+
+#[test]
+fn error_location() {
+    let mut file = header().to_bytes::<BigEndian>().unwrap();
+    file[21] = 0;
+
+    let e = SqliteHeader::from_bytes::<BigEndian>(&file).unwrap_err();
+    assert_eq!(
+        e.path().unwrap().to_string(),
+        "SqliteHeader.max_payload_fraction"
+    );
+    assert_eq!(e.offset(), Some(21));
+    assert!(matches!(
+        e.root_cause(),
+        CuisinerError::ConstMismatch { expected, found }
+            if *expected == Integer::from(64u8) && *found == Integer::from(0u8)
+    ));
+}
+
+#[test]
+fn report_every_error() {
+    let mut file = header().to_bytes::<BigEndian>().unwrap();
+    file[16..18].copy_from_slice(&[0x01, 0x23]);
+    file[21] = 0;
+    file[56..60].copy_from_slice(&[0, 0, 0, 9]);
+
+    let errors = SqliteHeader::from_bytes_report::<BigEndian>(&file).unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| (e.path().unwrap().to_string(), e.offset().unwrap()))
+            .collect::<Vec<_>>(),
+        [
+            ("SqliteHeader.page_size".to_string(), 16),
+            ("SqliteHeader.max_payload_fraction".to_string(), 21),
+            ("SqliteHeader.text_encoding".to_string(), 56),
+        ]
+    );
+
+    let file = header().to_bytes::<BigEndian>().unwrap();
+    let parsed = SqliteHeader::from_bytes_report::<BigEndian>(&file).unwrap();
+    assert_eq!(parsed.page_count, 12);
 }